@@ -0,0 +1,331 @@
+use rust_util::{bench::BenchOpts, days_in, Part};
+use std::{env, error::Error, ops::RangeInclusive, path::PathBuf};
+
+pub enum Command {
+  Run {
+    /// Save this run's answers as the accepted ones
+    record: bool,
+    /// Let `record` replace accepted answers and ones known to be wrong
+    force: bool,
+  },
+  Bench {
+    opts: BenchOpts,
+    history: HistoryOpts,
+    /// Bench every variant of each day against its own solution, failing if
+    /// any of them answers differently
+    variants: bool,
+  },
+  /// Posts a single part's answer, solving for it unless one is given
+  Submit { answer: Option<String> },
+  /// Scaffolds a new day from the template
+  New,
+  /// Lists the implemented days of each year, and those still missing
+  List,
+}
+
+/// Where bench results are kept and how new runs are judged against them
+pub struct HistoryOpts {
+  pub path: PathBuf,
+  /// Compare against this commit rather than the latest run that didn't regress.
+  /// Anything git can resolve, eg a branch, tag or abbreviated hash
+  pub baseline: Option<String>,
+  /// Slowdown of the median that counts as a regression, eg `0.1` for 10%.
+  /// Regressed runs are never a baseline, so a slowdown stays flagged until
+  /// it's fixed or a run with a higher threshold accepts it.
+  pub threshold: f64,
+  pub save: bool,
+}
+
+impl Default for HistoryOpts {
+  fn default() -> Self {
+    HistoryOpts {
+      path: PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../bench_history.json"),
+      baseline: None,
+      threshold: 0.1,
+      save: true,
+    }
+  }
+}
+
+pub struct Args {
+  pub command: Command,
+  /// Years to run, or every known year when not given
+  pub years: Option<RangeInclusive<usize>>,
+  /// Days to run, or every implemented day when `--all` is given
+  pub days: Option<RangeInclusive<usize>>,
+  pub parts: Vec<Part>,
+  /// Include days marked `#[aoc(slow)]` when running more than one day
+  pub slow: bool,
+  /// Run this `dayN_<variant>.rs` instead of each day's own solution
+  pub variant: Option<String>,
+}
+
+impl Args {
+  pub fn parse(args: impl Iterator<Item = String>) -> Result<Self, Box<dyn Error>> {
+    let mut years = None;
+    let mut days = None;
+    let mut all = false;
+    let mut slow = false;
+    let mut variant = None;
+    let mut parts = Part::ALL.to_vec();
+    let mut args = args.skip(1).peekable();
+    let mut command = match args.peek().map(|s| s.as_str()) {
+      Some("bench") => {
+        args.next();
+        Command::Bench {
+          opts: BenchOpts::default(),
+          history: HistoryOpts::default(),
+          variants: false,
+        }
+      }
+      Some("submit") => {
+        args.next();
+        Command::Submit { answer: None }
+      }
+      Some("new") => {
+        args.next();
+        Command::New
+      }
+      Some("list") => {
+        args.next();
+        Command::List
+      }
+      _ => Command::Run {
+        record: false,
+        force: false,
+      },
+    };
+    while let Some(arg) = args.next() {
+      match (arg.as_str(), &mut command) {
+        ("--year" | "-y", _) => years = Some(parse_range(&value(&mut args, &arg)?)?),
+        ("--day" | "-d", _) => days = Some(parse_range(&value(&mut args, &arg)?)?),
+        ("--part" | "-p", _) => parts = vec![value(&mut args, &arg)?.parse()?],
+        ("--all" | "-a", _) => all = true,
+        ("--slow", Command::Run { .. } | Command::Bench { .. }) => slow = true,
+        ("--variant", Command::Run { .. } | Command::Bench { .. }) => {
+          variant = Some(value(&mut args, &arg)?)
+        }
+        ("--variants", Command::Bench { variants, .. }) => *variants = true,
+        ("--record", Command::Run { record, .. }) => *record = true,
+        ("--force", Command::Run { force, .. }) => *force = true,
+        ("--runs" | "-n", Command::Bench { opts, .. }) => {
+          opts.runs = value(&mut args, &arg)?.parse()?
+        }
+        ("--warmup" | "-w", Command::Bench { opts, .. }) => {
+          opts.warmup = value(&mut args, &arg)?.parse()?
+        }
+        ("--threshold", Command::Bench { history, .. }) => {
+          history.threshold = value(&mut args, &arg)?.parse::<f64>()? / 100.0
+        }
+        ("--baseline", Command::Bench { history, .. }) => {
+          history.baseline = Some(value(&mut args, &arg)?)
+        }
+        ("--history", Command::Bench { history, .. }) => {
+          history.path = env::current_dir()?.join(value(&mut args, &arg)?)
+        }
+        ("--no-save", Command::Bench { history, .. }) => history.save = false,
+        ("--answer", Command::Submit { answer }) => *answer = Some(value(&mut args, &arg)?),
+        // A bare day or range, as `cargo run <dayNumber>` used to take
+        _ if !arg.starts_with('-') && days.is_none() => days = Some(parse_range(&arg)?),
+        _ => return Err(format!("Unknown argument: {}", arg).into()),
+      }
+    }
+    if days.is_none() && !all && !matches!(command, Command::List) {
+      return Err("No Day given to run, pass --day or --all".into());
+    }
+    let single =
+      |r: &Option<RangeInclusive<usize>>| r.as_ref().is_some_and(|r| r.start() == r.end());
+    match command {
+      Command::Submit { .. } if !single(&years) || !single(&days) || parts.len() != 1 => {
+        return Err("submit needs exactly one --year, --day and --part".into());
+      }
+      Command::New if !single(&years) || !single(&days) => {
+        return Err("new needs exactly one --year and --day".into());
+      }
+      Command::New => {
+        let (year, day) = (
+          *years.as_ref().unwrap().start(),
+          *days.as_ref().unwrap().start(),
+        );
+        if day > days_in(year) {
+          return Err(format!("{} has no day {}", year, day).into());
+        }
+      }
+      Command::Run {
+        record: false,
+        force: true,
+      } => {
+        return Err("--force only applies to --record".into());
+      }
+      Command::Bench { variants: true, .. } if variant.is_some() => {
+        return Err("--variant and --variants can't be used together".into());
+      }
+      _ => {}
+    }
+    Ok(Args {
+      command,
+      years,
+      days,
+      parts,
+      slow,
+      variant,
+    })
+  }
+}
+
+fn value(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<String, Box<dyn Error>> {
+  args
+    .next()
+    .ok_or_else(|| format!("{} needs a value", flag).into())
+}
+
+/// Parses `N`, `A..B` or `A..=B` into an inclusive range
+fn parse_range(s: &str) -> Result<RangeInclusive<usize>, Box<dyn Error>> {
+  let range = match s.split_once("..") {
+    None => {
+      let v = s.parse::<usize>()?;
+      v..=v
+    }
+    Some((lo, hi)) => match hi.strip_prefix('=') {
+      Some(hi) => lo.parse::<usize>()?..=hi.parse::<usize>()?,
+      None => lo.parse::<usize>()?..=hi.parse::<usize>()?.checked_sub(1).ok_or("Empty range")?,
+    },
+  };
+  match range.is_empty() {
+    true => Err(format!("Empty range: {}", s).into()),
+    false => Ok(range),
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn args(s: &str) -> Result<Args, Box<dyn Error>> {
+    Args::parse(
+      std::iter::once("aoc")
+        .chain(s.split_whitespace())
+        .map(String::from),
+    )
+  }
+
+  #[test]
+  fn ranges() {
+    assert_eq!(parse_range("7").unwrap(), 7..=7);
+    assert_eq!(parse_range("3..=9").unwrap(), 3..=9);
+    assert_eq!(parse_range("3..9").unwrap(), 3..=8);
+    assert!(parse_range("9..=3").is_err());
+    assert!(parse_range("x").is_err());
+  }
+
+  #[test]
+  fn flags() {
+    let a = args("--year 2024 3..=9 --part 2").unwrap();
+    assert_eq!(a.years, Some(2024..=2024));
+    assert_eq!(a.days, Some(3..=9));
+    assert_eq!(a.parts, vec![Part::Two]);
+
+    let a = args("--all").unwrap();
+    assert_eq!(a.years, None);
+    assert_eq!(a.days, None);
+    assert_eq!(a.parts, Part::ALL.to_vec());
+
+    assert!(matches!(
+      a.command,
+      Command::Run {
+        record: false,
+        force: false
+      }
+    ));
+    let a = args("-y 2024 -d 3 --record").unwrap();
+    assert!(matches!(
+      a.command,
+      Command::Run {
+        record: true,
+        force: false
+      }
+    ));
+    let a = args("-y 2024 -d 3 --record --force").unwrap();
+    assert!(matches!(a.command, Command::Run { force: true, .. }));
+    assert!(args("-y 2024 -d 3 --force").is_err());
+    assert!(args("--year 2024").is_err());
+    assert!(args("--day 1 --part 3").is_err());
+  }
+
+  #[test]
+  fn bench() {
+    let a = args("bench -y 2024 -d 16 --runs 50 --warmup 0").unwrap();
+    let Command::Bench {
+      opts,
+      history,
+      variants,
+    } = a.command
+    else {
+      panic!("Expected bench")
+    };
+    assert_eq!((opts.runs, opts.warmup), (50, 0));
+    assert!(!variants);
+    assert_eq!(history.threshold, 0.1);
+    assert!(history.save);
+
+    let a = args("bench -d 16 --threshold 25 --baseline abc123 --no-save").unwrap();
+    let Command::Bench { history, .. } = a.command else {
+      panic!("Expected bench")
+    };
+    assert_eq!(history.threshold, 0.25);
+    assert_eq!(history.baseline.as_deref(), Some("abc123"));
+    assert!(!history.save);
+    assert_eq!(a.days, Some(16..=16));
+
+    assert!(args("-d 16 --runs 50").is_err());
+  }
+
+  #[test]
+  fn submit() {
+    let a = args("submit -y 2024 -d 3 -p 2 --answer 42").unwrap();
+    assert!(matches!(a.command, Command::Submit { answer: Some(ref v) } if v == "42"));
+    assert_eq!(a.parts, vec![Part::Two]);
+
+    assert!(args("submit -y 2024 -d 3").is_err());
+    assert!(args("submit -d 3 -p 1").is_err());
+    assert!(args("submit -y 2024 -d 3..=4 -p 1").is_err());
+  }
+
+  #[test]
+  fn new() {
+    let a = args("new --year 2025 --day 12").unwrap();
+    assert!(matches!(a.command, Command::New));
+    assert_eq!((a.years, a.days), (Some(2025..=2025), Some(12..=12)));
+    assert!(args("new --year 2025 --day 13").is_err());
+    assert!(args("new --year 2024 --day 25").is_ok());
+
+    assert!(args("new -d 13").is_err());
+    assert!(args("new -y 2025 --all").is_err());
+    assert!(args("new -y 2025 -d 13 --record").is_err());
+  }
+
+  #[test]
+  fn list() {
+    let a = args("list").unwrap();
+    assert!(matches!(a.command, Command::List));
+    assert_eq!(a.years, None);
+    assert!(args("list -y 2024").is_ok());
+    assert!(args("list --slow").is_err());
+
+    assert!(!args("--all").unwrap().slow);
+    assert!(args("--all --slow").unwrap().slow);
+    assert!(args("bench -d 1..=25 --slow").unwrap().slow);
+  }
+
+  #[test]
+  fn variants() {
+    let a = args("-y 2024 -d 16 --variant search").unwrap();
+    assert_eq!(a.variant.as_deref(), Some("search"));
+    let a = args("bench -y 2024 -d 16 --variants").unwrap();
+    assert!(matches!(a.command, Command::Bench { variants: true, .. }));
+    assert_eq!(a.variant, None);
+
+    assert!(args("-d 16 --variants").is_err());
+    assert!(args("bench -d 16 --variants --variant search").is_err());
+  }
+}
@@ -0,0 +1,633 @@
+extern crate dotenv;
+extern crate rust_util;
+
+mod cli;
+mod report;
+mod scaffold;
+
+use cli::{Args, Command, HistoryOpts};
+use report::Row;
+use rust_util::{
+  answers::{AnswerStore, Answers},
+  bench::BenchOpts,
+  client::AocClient,
+  days_in,
+  history::{self, History},
+  read_input,
+  submit::{self, Submission},
+  Answer, AocDay, DayInfo, DayResult, Part, Solver,
+};
+use std::{
+  env, error::Error, fmt::Display, ops::RangeInclusive, path::Path, process::ExitCode,
+  str::FromStr, time::Instant,
+};
+use tracing::Level;
+
+struct Year {
+  year: usize,
+  days: &'static [DayInfo],
+}
+
+impl Year {
+  fn info(&self, day: usize) -> Option<&'static DayInfo> {
+    self.days.iter().find(|d| d.day == day)
+  }
+
+  /// Finds the day's solver, or that of one of its variants, and reads its
+  /// input
+  fn load(&self, day: usize, variant: Option<&str>) -> Result<(Solver, String), rust_util::Error> {
+    let aoc_day = AocDay::D(self.year, day);
+    let solver = self
+      .info(day)
+      .and_then(|info| info.solver(variant))
+      .ok_or(rust_util::Error::UnknownDay(aoc_day))?;
+    Ok((solver, read_input(aoc_day)?))
+  }
+}
+
+const YEARS: [Year; 6] = [
+  Year {
+    year: 2020,
+    days: &y2020::DAYS,
+  },
+  Year {
+    year: 2021,
+    days: &y2021::DAYS,
+  },
+  Year {
+    year: 2022,
+    days: &y2022::DAYS,
+  },
+  Year {
+    year: 2023,
+    days: &y2023::DAYS,
+  },
+  Year {
+    year: 2024,
+    days: &y2024::DAYS,
+  },
+  Year {
+    year: 2025,
+    days: &y2025::DAYS,
+  },
+];
+
+/// A failure of the whole command, exiting with `code`
+#[derive(Debug)]
+struct Failure {
+  message: String,
+  code: u8,
+}
+
+impl Failure {
+  /// Bad arguments, or a year or day that doesn't exist
+  fn usage(e: impl Display) -> Box<dyn Error> {
+    Box::new(Failure {
+      message: e.to_string(),
+      code: 2,
+    })
+  }
+}
+
+impl Display for Failure {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}", self.message)
+  }
+}
+
+impl Error for Failure {}
+
+/// The exit code for each kind of error, as listed in the README
+fn exit_code(e: &(dyn Error + 'static)) -> u8 {
+  if let Some(f) = e.downcast_ref::<Failure>() {
+    return f.code;
+  }
+  match e.downcast_ref::<rust_util::Error>() {
+    Some(rust_util::Error::UnknownDay(_)) => 2,
+    Some(rust_util::Error::InputMissing(_) | rust_util::Error::InputInvalid { .. }) => 3,
+    Some(rust_util::Error::DownloadFailed { .. }) => 4,
+    Some(rust_util::Error::ParseFailed { .. }) => 5,
+    Some(rust_util::Error::SolverFailed { .. } | rust_util::Error::Unsolved) => 6,
+    Some(rust_util::Error::InputUnreadable { .. }) => 7,
+    None => 1,
+  }
+}
+
+fn main() -> ExitCode {
+  tracing_subscriber::fmt()
+    .with_max_level(Level::from_str("INFO").unwrap())
+    .with_target(false)
+    .without_time()
+    .init();
+
+  dotenv::dotenv().ok();
+  match try_main() {
+    Ok(()) => ExitCode::SUCCESS,
+    Err(e) => {
+      eprintln!("Error: {}", e);
+      ExitCode::from(exit_code(e.as_ref()))
+    }
+  }
+}
+
+fn try_main() -> Result<(), Box<dyn Error>> {
+  let args = Args::parse(env::args()).map_err(Failure::usage)?;
+
+  let years = match &args.years {
+    Some(range) => range
+      .clone()
+      .map(|year| {
+        YEARS
+          .iter()
+          .find(|y| y.year == year)
+          .ok_or_else(|| Failure::usage(format!("Unknown year given: {}", year)))
+      })
+      .collect::<Result<Vec<_>, _>>()?,
+    None => YEARS.iter().collect(),
+  };
+  if let Command::List = args.command {
+    list(&years);
+    return Ok(());
+  }
+
+  let (mut selected, skipped) = select(&years, args.days.as_ref(), args.slow)?;
+  if let Some(name) = &args.variant {
+    selected = with_variant(selected, name)?;
+  }
+  if !skipped.is_empty() {
+    println!(
+      "Skipping slow day(s) {}, pass --slow to include them",
+      skipped.join(", ")
+    );
+  }
+
+  match &args.command {
+    Command::Run { record, force } => run(
+      &selected,
+      &args.parts,
+      args.variant.as_deref(),
+      record.then_some(*force),
+    ),
+    Command::Bench {
+      opts,
+      history,
+      variants,
+    } => bench(
+      &selected,
+      &args.parts,
+      opts,
+      history,
+      args.variant.as_deref(),
+      *variants,
+    ),
+    Command::Submit { answer } => match selected.as_slice() {
+      [(y, day)] => submit(y, *day, args.parts[0], answer.clone()),
+      _ => Err("Unknown day given".into()),
+    },
+    Command::List => Ok(()),
+    Command::New => match selected.as_slice() {
+      [(y, day)] => {
+        let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("../y{}", y.year));
+        scaffold::new_day(&crate_dir, y.year, *day)
+      }
+      _ => Err("Unknown day given".into()),
+    },
+  }
+}
+
+/// Days to run, each with the year it belongs to
+type Selected<'a> = Vec<(&'a Year, usize)>;
+
+/// The days to run from `years`, and the slow ones left out
+fn select<'a>(
+  years: &[&'a Year],
+  days: Option<&RangeInclusive<usize>>,
+  slow: bool,
+) -> Result<(Selected<'a>, Vec<String>), Box<dyn Error>> {
+  let mut selected = Vec::new();
+  let mut skipped = Vec::new();
+  for &y in years {
+    match days {
+      // A single day of a single year is always attempted so a missing one
+      // is reported. Across years it runs wherever that day exists.
+      Some(range) if range.start() == range.end() => {
+        if years.len() == 1 || y.info(*range.start()).is_some() {
+          selected.push((y, *range.start()))
+        }
+      }
+      range => {
+        for d in y.days {
+          if range.is_some_and(|r| !r.contains(&d.day)) {
+            continue;
+          }
+          match d.slow && !slow {
+            true => skipped.push(AocDay::D(y.year, d.day).to_string()),
+            false => selected.push((y, d.day)),
+          }
+        }
+      }
+    }
+  }
+  match days {
+    Some(range) if range.start() == range.end() && selected.is_empty() => Err(Failure::usage(
+      format!("No year has a day {}", range.start()),
+    )),
+    _ => Ok((selected, skipped)),
+  }
+}
+
+/// Narrows `selected` to the days that have the variant `name`, failing only
+/// if none do. Unknown days are kept so running them reports as much.
+fn with_variant<'a>(
+  mut selected: Selected<'a>,
+  name: &str,
+) -> Result<Selected<'a>, Box<dyn Error>> {
+  let single = match selected.as_slice() {
+    [(y, day)] => Some(AocDay::D(y.year, *day)),
+    _ => None,
+  };
+  selected.retain(|(y, day)| {
+    y.info(*day)
+      .is_none_or(|info| info.solver(Some(name)).is_some())
+  });
+  match (selected.is_empty(), single) {
+    (false, _) => Ok(selected),
+    (true, Some(day)) => Err(Failure::usage(format!("{} has no variant `{}`", day, name))),
+    (true, None) => Err(Failure::usage(format!(
+      "No selected day has a variant `{}`",
+      name
+    ))),
+  }
+}
+
+/// Prints every implemented day of each year, then which are missing
+fn list(years: &[&Year]) {
+  for y in years {
+    println!("\n== {} ==", y.year);
+    for d in y.days {
+      println!(
+        "{:>2}  {:<32} {:<6} y{}/{}",
+        d.day,
+        d.title.unwrap_or("-"),
+        if d.slow { "slow" } else { "" },
+        y.year,
+        d.path
+      );
+    }
+    let missing: Vec<String> = (1..=days_in(y.year))
+      .filter(|day| y.info(*day).is_none())
+      .map(|day| day.to_string())
+      .collect();
+    if !missing.is_empty() {
+      println!("Missing: {}", missing.join(", "));
+    }
+  }
+}
+
+/// Accepted answers live under `answers/` at the repo root unless
+/// `AOC_ANSWERS_DIR` points elsewhere
+fn answer_store() -> AnswerStore {
+  match env::var("AOC_ANSWERS_DIR") {
+    Ok(dir) => AnswerStore::new(dir),
+    Err(_) => AnswerStore::new(Path::new(env!("CARGO_MANIFEST_DIR")).join("../answers")),
+  }
+}
+
+/// Records the day's answers if asked to, forcing over accepted ones when
+/// the flag says so, then loads the accepted ones to check against. A broken
+/// answer file or a refused answer fails only this day, so it comes back as
+/// an error message alongside whatever could be loaded.
+fn sync_answers(
+  store: &AnswerStore,
+  day: AocDay,
+  to_record: Option<(&DayResult, bool)>,
+) -> (Answers, Option<String>) {
+  let recorded = to_record.map_or(Ok(()), |(res, force)| {
+    res.parts.iter().try_for_each(|p| match &p.answer {
+      Ok(ans) => store.record(day, p.part, ans, force),
+      Err(_) => Ok(()),
+    })
+  });
+  let recorded = recorded.map_err(|e| format!("Recording answers failed: {}", e));
+  match (store.load(day), recorded) {
+    (Ok(answers), Ok(())) => (answers, None),
+    (Ok(answers), Err(e)) => (answers, Some(e)),
+    (Err(e), _) => (
+      Answers::default(),
+      Some(format!(
+        "Loading {} failed: {}",
+        store.path(day).display(),
+        e
+      )),
+    ),
+  }
+}
+
+/// Runs each selected day and checks it against the accepted answers. With
+/// `record` set its answers are saved too, replacing accepted ones only if
+/// it's `true`.
+fn run(
+  selected: &[(&Year, usize)],
+  parts: &[Part],
+  variant: Option<&str>,
+  record: Option<bool>,
+) -> Result<(), Box<dyn Error>> {
+  let store = answer_store();
+  let mut rows = Vec::new();
+  for &(y, day) in selected {
+    match variant {
+      Some(name) => println!("\n== {} ({}) ==", AocDay::D(y.year, day), name),
+      None => println!("\n== {} ==", AocDay::D(y.year, day)),
+    }
+    let now = Instant::now();
+    let result = y
+      .load(day, variant)
+      .and_then(|(solver, input)| solver(input, parts));
+    let elapsed = now.elapsed();
+    let aoc_day = AocDay::D(y.year, day);
+    let to_record = result.as_ref().ok().zip(record);
+    let (expected, store_error) = sync_answers(&store, aoc_day, to_record);
+    let row = Row {
+      day: aoc_day,
+      result,
+      elapsed,
+      expected,
+      store_error,
+    };
+    report::print_day(&row);
+    rows.push(row);
+  }
+  report::print_summary(&rows);
+
+  let failed: Vec<&Row> = rows.iter().filter(|r| !r.is_ok()).collect();
+  match failed.first() {
+    None => Ok(()),
+    Some(first) => {
+      let code = match &first.result {
+        Err(e) => exit_code(e),
+        Ok(_) if first.solver_failed() => 6,
+        // Every part ran, so an answer contradicted the accepted one or the
+        // answer file couldn't be used
+        Ok(_) => 1,
+      };
+      Err(Box::new(Failure {
+        message: format!("{} day(s) failed", failed.len()),
+        code,
+      }))
+    }
+  }
+}
+
+fn bench(
+  selected: &[(&Year, usize)],
+  parts: &[Part],
+  opts: &BenchOpts,
+  hist_opts: &HistoryOpts,
+  variant: Option<&str>,
+  all_variants: bool,
+) -> Result<(), Box<dyn Error>> {
+  let mut history = History::load(&hist_opts.path)?;
+  let commit = history::current_commit();
+  let baseline = hist_opts.baseline.as_deref().map(history::resolve_commit);
+  let mut failed = Vec::new();
+  let mut regressed = 0;
+  let mut mismatched = 0;
+  for &(y, day) in selected {
+    let aoc_day = AocDay::D(y.year, day);
+    println!(
+      "\n== {} ({} runs, {} warmup) ==",
+      aoc_day, opts.runs, opts.warmup
+    );
+    let result = y
+      .load(day, variant)
+      .map_err(|e| e.into())
+      .and_then(|(solver, input)| {
+        let mut solvers = vec![(variant.unwrap_or("default"), solver)];
+        if all_variants {
+          let info = y.info(day).unwrap();
+          solvers.extend(info.variants.iter().map(|v| (v.name, v.solver)));
+        }
+        solvers
+          .into_iter()
+          .map(|(name, solver)| Ok((name, rust_util::bench::bench(solver, &input, parts, opts)?)))
+          .collect::<Result<Vec<_>, Box<dyn Error>>>()
+      });
+    match result {
+      Ok(results) => {
+        for (name, res) in results.iter() {
+          if results.len() > 1 {
+            println!("-- {} --", name);
+          }
+          report::print_bench(res);
+        }
+        if results.len() > 1 {
+          report::print_variants(&results);
+          let base = &results[0].1;
+          if results[1..]
+            .iter()
+            .any(|(_, res)| !res.mismatches(base).is_empty())
+          {
+            mismatched += 1;
+          }
+        }
+        // History only follows each day's own solution
+        if variant.is_none() {
+          let res = &results[0].1;
+          let cmp = history.compare(aoc_day, res, baseline.as_deref(), hist_opts.threshold);
+          report::print_comparison(&cmp);
+          // An explicit baseline that was never benched would otherwise turn
+          // regression checks off without a word
+          if let (Some(rev), true) = (&hist_opts.baseline, cmp.is_empty()) {
+            println!("Error: No run of {} recorded at {}", aoc_day, rev);
+            failed.push(2);
+          }
+          if cmp.iter().any(|c| c.regressed) {
+            regressed += 1;
+          }
+          history.record(aoc_day, res, &commit, &cmp);
+        }
+      }
+      Err(e) => {
+        println!("Error: {}", e);
+        failed.push(exit_code(e.as_ref()));
+      }
+    }
+  }
+  if hist_opts.save {
+    history.save(&hist_opts.path)?;
+  }
+
+  match (failed.first(), regressed, mismatched) {
+    (None, 0, 0) => Ok(()),
+    (None, _, n) if n > 0 => {
+      Err(format!("{} day(s) have variants disagreeing on an answer", n).into())
+    }
+    (None, n, _) => Err(format!("{} day(s) regressed", n).into()),
+    (Some(code), _, _) => Err(Box::new(Failure {
+      message: format!("{} day(s) failed", failed.len()),
+      code: *code,
+    })),
+  }
+}
+
+fn submit(y: &Year, day: usize, part: Part, answer: Option<String>) -> Result<(), Box<dyn Error>> {
+  let aoc_day = AocDay::D(y.year, day);
+  let answer = match answer {
+    Some(v) => Answer::from(v),
+    None => {
+      let (solver, input) = y.load(day, None)?;
+      let mut res = solver(input, &[part])?;
+      let res = res.parts.remove(0);
+      let unsolved = res.is_unsolved();
+      match res.answer {
+        Ok(v) => v,
+        // An unsolved or broken part has nothing worth guessing with
+        Err(e) if unsolved => {
+          println!("Refusing to submit {} {}, it isn't solved", aoc_day, part);
+          return Err(e);
+        }
+        Err(source) => {
+          println!("Refusing to submit {} {}, it has no answer", aoc_day, part);
+          return Err(rust_util::Error::SolverFailed { part, source }.into());
+        }
+      }
+    }
+  };
+
+  let store = answer_store();
+  if let Some(known) = store.load(aoc_day)?.get(part) {
+    return match *known == answer {
+      true => {
+        println!(
+          "{} {}: {} is already the accepted answer",
+          aoc_day, part, known
+        );
+        Ok(())
+      }
+      false => Err(format!("{} {} was already solved with {}", aoc_day, part, known).into()),
+    };
+  }
+
+  println!("Submitting {} for {} {}", answer, aoc_day, part);
+  let res = submit::submit(&AocClient::from_env()?, &store, aoc_day, part, &answer)?;
+  println!("{}", res);
+  match res {
+    Submission::Correct | Submission::AlreadySolved => Ok(()),
+    _ => Err("Answer was not accepted".into()),
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn exit_codes() {
+    let day = AocDay::D(2024, 3);
+    let err: Box<dyn Error> = rust_util::Error::UnknownDay(day).into();
+    assert_eq!(exit_code(err.as_ref()), 2);
+    let err: Box<dyn Error> = rust_util::Error::InputMissing("input/2024/d3".into()).into();
+    assert_eq!(exit_code(err.as_ref()), 3);
+    let err: Box<dyn Error> = rust_util::Error::InputInvalid {
+      path: "input/2024/d3".into(),
+      reason: "it is empty".into(),
+    }
+    .into();
+    assert_eq!(exit_code(err.as_ref()), 3);
+    let err: Box<dyn Error> = rust_util::Error::InputUnreadable {
+      path: "input/2024/d3".into(),
+      source: std::io::ErrorKind::InvalidData.into(),
+    }
+    .into();
+    assert_eq!(exit_code(err.as_ref()), 7);
+    let err: Box<dyn Error> = rust_util::Error::SolverFailed {
+      part: Part::One,
+      source: "Not solved".into(),
+    }
+    .into();
+    assert_eq!(exit_code(err.as_ref()), 6);
+    assert_eq!(
+      exit_code(Failure::usage("Unknown argument: -x").as_ref()),
+      2
+    );
+    assert_eq!(
+      exit_code(Box::<dyn Error>::from("Answer was not accepted").as_ref()),
+      1
+    );
+  }
+
+  #[test]
+  fn single_day_across_years() {
+    let years: Vec<&Year> = YEARS.iter().collect();
+    let (selected, _) = select(&years, Some(&(16..=16)), false).unwrap();
+    assert!(!selected.is_empty());
+    assert!(selected
+      .iter()
+      .all(|(y, day)| *day == 16 && y.info(16).is_some()));
+    assert!(select(&years, Some(&(26..=26)), false).is_err());
+
+    let y2025 = [&YEARS[5]];
+    let (selected, _) = select(&y2025, Some(&(16..=16)), false).unwrap();
+    assert_eq!(selected.len(), 1);
+  }
+
+  #[test]
+  fn variant_selection() {
+    let y2024 = [&YEARS[4]];
+    let (selected, _) = select(&y2024, Some(&(1..=25)), true).unwrap();
+    let with_search = with_variant(selected.clone(), "search").unwrap();
+    let days: Vec<usize> = with_search.iter().map(|(_, day)| *day).collect();
+    assert_eq!(days, vec![16]);
+    assert!(with_variant(selected, "nope").is_err());
+
+    let (selected, _) = select(&y2024, Some(&(1..=1)), true).unwrap();
+    assert!(with_variant(selected, "search").is_err());
+  }
+
+  #[test]
+  fn broken_answer_files() {
+    let dir = env::temp_dir().join(format!("aoc-answers-{}", std::process::id()));
+    let store = AnswerStore::new(&dir);
+    let (good, bad) = (AocDay::D(2024, 1), AocDay::D(2024, 2));
+    store
+      .record(good, Part::One, &Answer::from(7), false)
+      .unwrap();
+    std::fs::write(store.path(bad), "part1 = [not toml").unwrap();
+
+    let (answers, err) = sync_answers(&store, good, None);
+    let (broken, broken_err) = sync_answers(&store, bad, None);
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(answers.get(Part::One), Some(&Answer::from(7)));
+    assert_eq!(err, None);
+    assert_eq!(broken.get(Part::One), None);
+    assert!(broken_err.unwrap().starts_with("Loading "));
+  }
+
+  #[test]
+  fn record_keeps_accepted_answers() {
+    let dir = env::temp_dir().join(format!("aoc-record-{}", std::process::id()));
+    let store = AnswerStore::new(&dir);
+    let day = AocDay::D(2024, 1);
+    store
+      .record(day, Part::One, &Answer::from(7), false)
+      .unwrap();
+    let res = DayResult {
+      parse: Default::default(),
+      parts: vec![rust_util::PartResult {
+        part: Part::One,
+        answer: Ok(Answer::from(1)),
+        elapsed: Default::default(),
+      }],
+    };
+
+    let (kept, err) = sync_answers(&store, day, Some((&res, false)));
+    let (forced, forced_err) = sync_answers(&store, day, Some((&res, true)));
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(kept.get(Part::One), Some(&Answer::from(7)));
+    assert!(err.unwrap().starts_with("Recording answers failed"));
+    assert_eq!(forced.get(Part::One), Some(&Answer::from(1)));
+    assert_eq!(forced_err, None);
+  }
+}
@@ -0,0 +1,254 @@
+use rust_util::{
+  answers::{Answer, Answers, Verdict},
+  bench::{BenchResult, Stats},
+  history::Comparison,
+  AocDay, DayResult, Part,
+};
+use std::time::Duration;
+
+/// One line of the end-of-run summary table
+pub struct Row {
+  pub day: AocDay,
+  pub result: Result<DayResult, rust_util::Error>,
+  /// Wall clock for the whole day, including reading the input
+  pub elapsed: Duration,
+  /// Previously accepted answers to check the result against
+  pub expected: Answers,
+  /// Why the answer file couldn't be read or written, failing the day
+  pub store_error: Option<String>,
+}
+
+impl Row {
+  /// Every part ran without error, or isn't solved yet, and none contradict
+  /// a known answer
+  pub fn is_ok(&self) -> bool {
+    self.store_error.is_none()
+      && self.result.as_ref().is_ok_and(|r| r.is_ok())
+      && !self
+        .verdicts()
+        .iter()
+        .any(|(_, v)| matches!(v, Verdict::Fail { .. }))
+  }
+
+  /// Some part returned an error rather than an answer
+  pub fn solver_failed(&self) -> bool {
+    self.result.as_ref().is_ok_and(|r| !r.is_ok())
+  }
+
+  fn verdicts(&self) -> Vec<(Part, Verdict)> {
+    let Ok(res) = &self.result else {
+      return vec![];
+    };
+    res
+      .parts
+      .iter()
+      .filter_map(|p| {
+        let ans = p.answer.as_ref().ok()?;
+        Some((p.part, self.expected.check(p.part, ans)))
+      })
+      .collect()
+  }
+
+  fn check(&self) -> &'static str {
+    let verdicts = self.verdicts();
+    if self.store_error.is_some() {
+      "ERROR"
+    } else if verdicts
+      .iter()
+      .any(|(_, v)| matches!(v, Verdict::Fail { .. }))
+    {
+      "FAIL"
+    } else if !verdicts.is_empty() && verdicts.iter().all(|(_, v)| *v == Verdict::Pass) {
+      "PASS"
+    } else {
+      "-"
+    }
+  }
+
+  fn answer(&self, part: Part) -> String {
+    let Ok(res) = &self.result else {
+      return "error".into();
+    };
+    match res.get(part) {
+      None => "-".into(),
+      Some(p) if p.is_unsolved() => "-".into(),
+      Some(p) => match &p.answer {
+        Err(_) => "error".into(),
+        Ok(Answer::MultiLine(_)) => "(multi-line)".into(),
+        Ok(v) => {
+          let v = v.to_string();
+          match v.chars().count() > 18 {
+            true => format!("{}…", v.chars().take(17).collect::<String>()),
+            false => v,
+          }
+        }
+      },
+    }
+  }
+
+  fn parse_time(&self) -> String {
+    match &self.result {
+      Ok(res) => format!("{:.2?}", res.parse),
+      Err(_) => "-".into(),
+    }
+  }
+
+  fn part_time(&self, part: Part) -> String {
+    match self.result.as_ref().ok().and_then(|r| r.get(part)) {
+      Some(p) => format!("{:.2?}", p.elapsed),
+      None => "-".into(),
+    }
+  }
+}
+
+/// Prints a day's answers followed by how long each phase took
+pub fn print_day(row: &Row) {
+  match &row.result {
+    Ok(res) => {
+      for p in res.parts.iter() {
+        match &p.answer {
+          Ok(v) => match row.expected.check(p.part, v) {
+            Verdict::Pass => println!("{}: {} [PASS]", p.part, v),
+            Verdict::Fail { expected } => {
+              println!("{}: {} [FAIL, expected {}]", p.part, v, expected)
+            }
+            Verdict::Unknown => println!("{}: {}", p.part, v),
+          },
+          Err(e) if p.is_unsolved() => println!("{}: {}", p.part, e),
+          Err(e) => println!("{}: {:?}", p.part, e),
+        }
+      }
+      let phases = res
+        .parts
+        .iter()
+        .map(|p| format!("{}: {:.2?}", p.part, p.elapsed))
+        .collect::<Vec<_>>();
+      println!("Parse: {:.2?} | {}", res.parse, phases.join(" | "));
+    }
+    Err(e) => println!("Error: {}", e),
+  }
+  if let Some(e) = &row.store_error {
+    println!("Error: {}", e);
+  }
+  println!("Elapsed: {:.2?}", row.elapsed);
+}
+
+pub fn print_summary(rows: &[Row]) {
+  if rows.is_empty() {
+    return;
+  }
+  println!(
+    "\n{:<14} {:<18} {:<18} {:<5} {:>10} {:>10} {:>10} {:>10}",
+    "Day", "Part 1", "Part 2", "Check", "Parse", "P1 time", "P2 time", "Total"
+  );
+  for row in rows {
+    println!(
+      "{:<14} {:<18} {:<18} {:<5} {:>10} {:>10} {:>10} {:>10}",
+      row.day.to_string(),
+      row.answer(Part::One),
+      row.answer(Part::Two),
+      row.check(),
+      row.parse_time(),
+      row.part_time(Part::One),
+      row.part_time(Part::Two),
+      format!("{:.2?}", row.elapsed)
+    );
+  }
+  let total: Duration = rows.iter().map(|r| r.elapsed).sum();
+  let failed = rows.iter().filter(|r| !r.is_ok()).count();
+  println!(
+    "{:<91} {:>10}",
+    format!("Total ({} ok, {} failed)", rows.len() - failed, failed),
+    format!("{:.2?}", total)
+  );
+}
+
+pub fn print_bench(res: &BenchResult) {
+  println!(
+    "{:<8} {:>10} {:>10} {:>10} {:>10}",
+    "Phase", "Min", "Median", "Mean", "Std Dev"
+  );
+  let row = |name: String, s: &Stats| {
+    println!(
+      "{:<8} {:>10} {:>10} {:>10} {:>10}",
+      name,
+      format!("{:.2?}", s.min),
+      format!("{:.2?}", s.median),
+      format!("{:.2?}", s.mean),
+      format!("{:.2?}", s.std_dev)
+    )
+  };
+  row("Parse".into(), &res.parse);
+  for (part, stats) in res.parts.iter() {
+    row(part.to_string(), stats);
+  }
+  for part in res.unsolved.iter() {
+    println!("{:<8} skipped, not solved", part.to_string());
+  }
+}
+
+/// Prints the median of each phase for every variant benched on a day, along
+/// with how the total compares to the first and any part whose answer doesn't
+/// match the first's
+pub fn print_variants(results: &[(&str, BenchResult)]) {
+  let total = |res: &BenchResult| {
+    res.parse.median + res.parts.iter().map(|(_, s)| s.median).sum::<Duration>()
+  };
+  println!(
+    "{:<12} {:>10} {:>10} {:>10} {:>10}",
+    "Variant", "Parse", "Part 1", "Part 2", "Total"
+  );
+  let base = total(&results[0].1).as_secs_f64();
+  for (name, res) in results {
+    let part = |part: Part| match res.parts.iter().find(|(p, _)| *p == part) {
+      Some((_, s)) => format!("{:.2?}", s.median),
+      None => "-".into(),
+    };
+    let t = total(res);
+    let mismatched = res.mismatches(&results[0].1);
+    println!(
+      "{:<12} {:>10} {:>10} {:>10} {:>10} ({:.2}x){}",
+      name,
+      format!("{:.2?}", res.parse.median),
+      part(Part::One),
+      part(Part::Two),
+      format!("{:.2?}", t),
+      t.as_secs_f64() / base,
+      match mismatched.is_empty() {
+        true => String::new(),
+        false => format!(
+          "  MISMATCH on {}",
+          mismatched
+            .iter()
+            .map(|p| p.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+        ),
+      }
+    );
+  }
+}
+
+/// Prints how each phase's median moved against its recorded baseline
+pub fn print_comparison(cmp: &[Comparison]) {
+  for c in cmp {
+    let phase = match c.phase.as_str() {
+      "parse" => "Parse",
+      "1" => "Part 1",
+      "2" => "Part 2",
+      other => other,
+    };
+    println!(
+      "{:<8} {:>10} -> {:>10} ({:+.1}%) vs {}{}",
+      phase,
+      format!("{:.2?}", c.baseline.median()),
+      format!("{:.2?}", c.median),
+      c.change * 100.0,
+      c.baseline.commit,
+      match c.regressed {
+        true => "  REGRESSION",
+        false => "",
+      }
+    );
+  }
+}
@@ -0,0 +1,101 @@
+use rust_util::{client::AocClient, days_in, examples, read_input, AocDay};
+use std::{
+  error::Error,
+  fs,
+  path::{Path, PathBuf},
+  time::SystemTime,
+};
+
+const TEMPLATE: &str = include_str!("../templates/day.rs");
+
+/// The template filled in for the given day
+pub fn render(year: usize, day: usize) -> String {
+  TEMPLATE
+    .replace("__YEAR__", &year.to_string())
+    .replace("__DAY__", &day.to_string())
+}
+
+/// Writes `day{day}.rs` into the year crate at `crate_dir`, refusing to
+/// overwrite an existing solution
+pub fn write_day(crate_dir: &Path, year: usize, day: usize) -> Result<PathBuf, Box<dyn Error>> {
+  if !(1..=days_in(year)).contains(&day) {
+    return Err(format!("{} has no day {}", year, day).into());
+  }
+  let path = crate_dir.join("src").join(format!("day{}.rs", day));
+  if path.exists() {
+    return Err(format!("{} already exists", path.display()).into());
+  }
+  fs::write(&path, render(year, day))?;
+  // The solutions macro lists src/ at compile time, but cargo only rebuilds
+  // when a file it already knows about changes
+  fs::File::options()
+    .append(true)
+    .open(crate_dir.join("src/lib.rs"))?
+    .set_modified(SystemTime::now())?;
+  Ok(path)
+}
+
+/// Scaffolds the day and caches its input and examples. Those may not be
+/// out yet, so failing to fetch them is only reported
+pub fn new_day(crate_dir: &Path, year: usize, day: usize) -> Result<(), Box<dyn Error>> {
+  let path = write_day(crate_dir, year, day)?;
+  println!("Wrote {}", path.display());
+
+  let day = AocDay::D(year, day);
+  if let Err(e) = read_input(day) {
+    println!("Could not fetch input: {}", e);
+  }
+  match AocClient::from_env().and_then(|c| examples::fetch_examples(&c, day)) {
+    Ok(paths) => println!("Cached {} example(s)", paths.len()),
+    Err(e) => println!("Could not fetch examples: {}", e),
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use std::env;
+
+  #[test]
+  fn renders() {
+    let day = render(2024, 7);
+    assert!(day.contains("read_example(AocDay::D(2024, 7), 1)"));
+    assert!(!day.contains("__"));
+  }
+
+  /// The template compiled as a day of its own, so this fails to build when
+  /// it falls behind `Day` or `Answer`. Declared inside the test so the
+  /// template's own `example` test isn't collected and run.
+  #[test]
+  fn template_compiles() {
+    #[allow(dead_code, unused_variables, unnameable_test_items)]
+    mod template {
+      const __YEAR__: usize = 2024;
+      const __DAY__: usize = 7;
+      include!("../templates/day.rs");
+    }
+    use rust_util::Day;
+
+    let solve = template::Solve::try_from(render(2024, 7)).unwrap();
+    assert!(solve.p1().is_err());
+    assert!(solve.p2().is_err());
+  }
+
+  #[test]
+  fn writes_day() {
+    let dir = env::temp_dir().join(format!("aoc-new-{}", std::process::id()));
+    fs::create_dir_all(dir.join("src")).unwrap();
+    fs::write(dir.join("src/lib.rs"), "").unwrap();
+
+    let path = write_day(&dir, 2024, 7).unwrap();
+    assert!(path.ends_with("src/day7.rs"));
+    assert_eq!(fs::read_to_string(&path).unwrap(), render(2024, 7));
+    assert!(write_day(&dir, 2024, 7).is_err());
+    assert!(write_day(&dir, 2024, 26).is_err());
+    assert!(write_day(&dir, 2025, 13).is_err());
+    assert!(write_day(&dir, 2025, 12).is_ok());
+
+    fs::remove_dir_all(dir).unwrap();
+  }
+}
@@ -0,0 +1,34 @@
+use rust_util::{Answer, Day};
+use std::error::Error;
+
+pub struct Solve {}
+impl TryFrom<String> for Solve {
+    type Error = Box<dyn Error>;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Ok(Solve {})
+    }
+}
+impl Day for Solve {
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+        Err(rust_util::Error::Unsolved.into())
+    }
+
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+        Err(rust_util::Error::Unsolved.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rust_util::{read_example, AocDay};
+
+    #[test]
+    fn example() {
+        let input = read_example(AocDay::D(__YEAR__, __DAY__), 1).unwrap();
+        let solve = Solve::try_from(input).unwrap();
+        assert_eq!(solve.p1().unwrap(), 1);
+        assert_eq!(solve.p2().unwrap(), 1);
+    }
+}
@@ -2,16 +2,86 @@ extern crate proc_macro;
 extern crate syn;
 
 use quote::quote;
-use std::path::PathBuf;
+use std::path::Path;
 
 use proc_macro::TokenStream;
 use regex::Regex;
+use syn::{parse::Parser, punctuated::Punctuated, Attribute, Item, Lit, Meta, NestedMeta, Token};
 
-fn find_solution_filenames(
-    full_path: &PathBuf,
-) -> Result<Vec<(usize, String, String)>, Box<dyn std::error::Error>> {
-    let mut results: Vec<(usize, String, String)> = Vec::new();
-    let expression = Regex::new("(day([0-9]+)).rs")?;
+/// A `dayN.rs`, or a `dayN_<variant>.rs` alternative to it, found in the
+/// crate's `src/`
+struct Solution {
+    day: usize,
+    variant: Option<String>,
+    module: String,
+    file: String,
+    meta: DayMeta,
+}
+
+/// What a day's `#[aoc(...)]` attribute says about it
+#[derive(Default)]
+struct DayMeta {
+    title: Option<String>,
+    slow: bool,
+}
+
+/// Reads `title = "..."` and `slow` out of the attribute's arguments
+fn parse_meta(args: Punctuated<NestedMeta, Token![,]>) -> syn::Result<DayMeta> {
+    let mut meta = DayMeta::default();
+    for arg in args {
+        match arg {
+            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("title") => match nv.lit {
+                Lit::Str(s) => meta.title = Some(s.value()),
+                lit => return Err(syn::Error::new_spanned(lit, "title must be a string")),
+            },
+            NestedMeta::Meta(Meta::Path(p)) if p.is_ident("slow") => meta.slow = true,
+            arg => {
+                return Err(syn::Error::new_spanned(
+                    arg,
+                    "expected `title = \"...\"` or `slow`",
+                ))
+            }
+        }
+    }
+    Ok(meta)
+}
+
+/// Whether the attribute is `#[aoc]`, either imported or by its full path
+fn is_aoc(attr: &Attribute) -> bool {
+    let segments: Vec<String> = attr
+        .path
+        .segments
+        .iter()
+        .map(|s| s.ident.to_string())
+        .collect();
+    segments == ["aoc"] || segments == ["rust_util_macro", "aoc"]
+}
+
+/// Reads the `#[aoc(...)]` attribute on the file's `Solve`, if it has one
+fn read_meta(source: &str) -> syn::Result<DayMeta> {
+    // A file that doesn't parse gets its errors reported when its module is
+    // compiled, which says far more than failing here would
+    let Ok(file) = syn::parse_file(source) else {
+        return Ok(DayMeta::default());
+    };
+    let attrs = file.items.iter().find_map(|item| match item {
+        Item::Struct(s) if s.ident == "Solve" => Some(&s.attrs),
+        Item::Enum(e) if e.ident == "Solve" => Some(&e.attrs),
+        _ => None,
+    });
+    match attrs.and_then(|attrs| attrs.iter().find(|a| is_aoc(a))) {
+        Some(attr) => match attr.parse_meta()? {
+            Meta::Path(_) => Ok(DayMeta::default()),
+            Meta::List(list) => parse_meta(list.nested),
+            meta => Err(syn::Error::new_spanned(meta, "expected `#[aoc(...)]`")),
+        },
+        None => Ok(DayMeta::default()),
+    }
+}
+
+fn find_solution_filenames(full_path: &Path) -> Result<Vec<Solution>, Box<dyn std::error::Error>> {
+    let mut results = Vec::new();
+    let expression = Regex::new(r"^(day([0-9]+)(?:_([a-z0-9_]+))?)\.rs$")?;
     for entry in std::fs::read_dir(full_path)? {
         let entry = entry?;
         let path = entry.path();
@@ -21,23 +91,40 @@ fn find_solution_filenames(
         let Some(strname) = filename.to_str() else {
             continue;
         };
-        if expression.is_match(strname) {
-            let caps = expression.captures(strname).unwrap();
-            results.push((
-                caps.get(2).unwrap().as_str().parse::<usize>().unwrap(),
-                caps.get(1).unwrap().as_str().to_string(),
-                caps.get(0).unwrap().as_str().to_string(),
-            ));
-        }
+        let Some(caps) = expression.captures(strname) else {
+            continue;
+        };
+        let meta = read_meta(&std::fs::read_to_string(&path)?)?;
+        results.push(Solution {
+            day: caps[2].parse::<usize>()?,
+            variant: caps.get(3).map(|v| v.as_str().to_string()),
+            module: caps[1].to_string(),
+            file: strname.to_string(),
+            meta,
+        });
     }
+    results.sort_by(|a, b| (a.day, &a.variant).cmp(&(b.day, &b.variant)));
     Ok(results)
 }
 
+/// Describes a day for `import_aoc_solutions!`, eg `#[aoc(title = "...", slow)]`
+/// on its `Solve`. Slow days are skipped unless the runner is asked for them.
+#[proc_macro_attribute]
+pub fn aoc(attr: TokenStream, item: TokenStream) -> TokenStream {
+    match Punctuated::<NestedMeta, Token![,]>::parse_terminated.parse(attr) {
+        Ok(args) => match parse_meta(args) {
+            Ok(_) => item,
+            Err(e) => e.to_compile_error().into(),
+        },
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
 #[proc_macro]
 pub fn import_aoc_solutions(_input: TokenStream) -> TokenStream {
     let root_path = "src/";
 
-    let solution_paths = {
+    let solutions = {
         let root = std::env::var("CARGO_MANIFEST_DIR").unwrap_or(".".into());
         let full_path = std::path::Path::new(&root).join(root_path);
         if full_path.is_dir() {
@@ -50,31 +137,114 @@ pub fn import_aoc_solutions(_input: TokenStream) -> TokenStream {
         }
     };
 
-    let mods: proc_macro2::TokenStream = solution_paths
+    let (days, variants): (Vec<&Solution>, Vec<&Solution>) =
+        solutions.iter().partition(|s| s.variant.is_none());
+    if let Some(orphan) = variants
         .iter()
-        .map(|(_, name, _)| format!("mod {};", name))
-        .collect::<Vec<String>>()
-        .join("\n")
-        .parse()
-        .unwrap();
+        .find(|v| days.iter().all(|d| d.day != v.day))
+    {
+        panic!(
+            "{} is a variant of day{}.rs, which doesn't exist",
+            orphan.file, orphan.day
+        );
+    }
+    let day_count = days.len();
 
-    let arms: proc_macro2::TokenStream = solution_paths
+    let mods: proc_macro2::TokenStream = solutions
         .iter()
-        .map(|(num, name, _)| format!("AocDay::D(_, {}) => {}::Solve::new(day)?.run(),", num, name))
+        .map(|s| format!("mod {};", s.module))
         .collect::<Vec<String>>()
         .join("\n")
         .parse()
         .unwrap();
 
+    let ident = |s: &Solution| syn::Ident::new(&s.module, proc_macro2::Span::call_site());
+    let infos = days.iter().map(|s| {
+        let day = s.day;
+        let module = &s.module;
+        let path = format!("{}{}", root_path, s.file);
+        let solve = ident(s);
+        let title = match &s.meta.title {
+            Some(t) => quote! { Some(#t) },
+            None => quote! { None },
+        };
+        let slow = s.meta.slow;
+        let day_variants = variants.iter().filter(|v| v.day == day).map(|v| {
+            let name = v.variant.as_deref().unwrap();
+            let module = &v.module;
+            let path = format!("{}{}", root_path, v.file);
+            let solve = ident(v);
+            quote! {
+                rust_util::Variant {
+                    name: #name,
+                    module: #module,
+                    path: #path,
+                    solver: #solve::Solve::run_input,
+                }
+            }
+        });
+        quote! {
+            rust_util::DayInfo {
+                day: #day,
+                module: #module,
+                path: #path,
+                title: #title,
+                slow: #slow,
+                solver: #solve::Solve::run_input,
+                variants: &[#(#day_variants),*],
+            }
+        }
+    });
+
     let expanded = quote! {
         #mods
-        fn run(day: AocDay) -> Result<String, Box<dyn Error>> {
-          match day {
-            #arms
-            _ => Err("Unknown day given".into()),
-          }
+
+        // Each day implements one of these, whichever it is provides `run_input`
+        #[allow(unused_imports)]
+        use rust_util::{BorrowedDay as _, Day as _};
+
+        /// Every day with a solution in this crate, in ascending order
+        pub static DAYS: [rust_util::DayInfo; #day_count] = [#(#infos),*];
+
+        pub fn run(
+            day: rust_util::AocDay,
+            parts: &[rust_util::Part],
+        ) -> Result<rust_util::DayResult, rust_util::Error> {
+            let rust_util::AocDay::D(_, n) = day;
+            match DAYS.iter().find(|d| d.day == n) {
+                Some(info) => (info.solver)(rust_util::read_input(day)?, parts),
+                None => Err(rust_util::Error::UnknownDay(day)),
+            }
         }
     };
 
     TokenStream::from(expanded)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn reads_solve_attribute() {
+        let meta = read_meta(
+            r##"
+            // #[aoc(title = "Not this one")]
+            const NOTE: &str = "#[aoc(slow)]";
+            #[rust_util_macro::aoc(title = "Fancy (with parens)]", slow)]
+            pub struct Solve;
+            "##,
+        )
+        .unwrap();
+        assert_eq!(meta.title.as_deref(), Some("Fancy (with parens)]"));
+        assert!(meta.slow);
+
+        let meta = read_meta("#[aoc]\npub struct Solve { n: usize }").unwrap();
+        assert_eq!((meta.title, meta.slow), (None, false));
+
+        let meta = read_meta("#[aoc(slow)]\nstruct Other;\npub struct Solve;").unwrap();
+        assert!(!meta.slow);
+
+        assert!(read_meta("#[aoc(fast)]\npub struct Solve;").is_err());
+    }
+}
@@ -0,0 +1,457 @@
+use std::{error::Error, fmt::Display, fs, path::PathBuf};
+
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::{write_atomic, AocDay, Part};
+
+/// What a part produced. Answers compare equal when they print the same, so
+/// `Int(7)`, `Uint(7)` and `Str("7")` are all the same answer
+#[derive(Clone, Debug, Deserialize)]
+#[serde(from = "Stored")]
+pub enum Answer {
+  Int(i64),
+  Uint(u64),
+  Str(String),
+  /// Text spanning lines, like letters drawn on a grid
+  MultiLine(String),
+}
+
+/// Anything the answer store may hold: TOML has no unsigned integers, so
+/// those come back as strings
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Stored {
+  Int(i64),
+  Str(String),
+}
+
+impl From<Stored> for Answer {
+  fn from(value: Stored) -> Self {
+    match value {
+      Stored::Int(v) => Answer::Int(v),
+      Stored::Str(v) => Answer::from(v),
+    }
+  }
+}
+
+impl Serialize for Answer {
+  fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+    match self {
+      Answer::Int(v) => s.serialize_i64(*v),
+      Answer::Uint(v) => match i64::try_from(*v) {
+        Ok(v) => s.serialize_i64(v),
+        Err(_) => s.serialize_str(&v.to_string()),
+      },
+      Answer::Str(v) | Answer::MultiLine(v) => s.serialize_str(v),
+    }
+  }
+}
+
+impl Answer {
+  /// The answer as a number, if it is one
+  pub fn as_i64(&self) -> Option<i64> {
+    match self {
+      Answer::Int(v) => Some(*v),
+      Answer::Uint(v) => i64::try_from(*v).ok(),
+      Answer::Str(v) => v.parse().ok(),
+      Answer::MultiLine(_) => None,
+    }
+  }
+}
+
+impl Display for Answer {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    match self {
+      Answer::Int(v) => write!(f, "{}", v),
+      Answer::Uint(v) => write!(f, "{}", v),
+      Answer::Str(v) | Answer::MultiLine(v) => write!(f, "{}", v),
+    }
+  }
+}
+
+impl PartialEq for Answer {
+  fn eq(&self, other: &Self) -> bool {
+    match (self, other) {
+      (Answer::Int(a), Answer::Int(b)) => a == b,
+      (Answer::Uint(a), Answer::Uint(b)) => a == b,
+      _ => self.to_string() == other.to_string(),
+    }
+  }
+}
+
+impl Eq for Answer {}
+
+/// Surrounding whitespace is never part of a single-line answer. Multi-line
+/// answers only lose their blank leading and trailing lines, since the spaces
+/// at the start of a row are part of the drawing
+impl From<String> for Answer {
+  fn from(value: String) -> Self {
+    let lines = trim_lines(&value);
+    match lines.contains('\n') {
+      true if lines.len() == value.len() => Answer::MultiLine(value),
+      true => Answer::MultiLine(lines.to_string()),
+      false => Answer::Str(lines.trim().to_string()),
+    }
+  }
+}
+
+fn trim_lines(value: &str) -> &str {
+  value.trim_matches(|c| c == '\n' || c == '\r')
+}
+
+impl From<&str> for Answer {
+  fn from(value: &str) -> Self {
+    Answer::from(value.to_string())
+  }
+}
+
+impl From<&String> for Answer {
+  fn from(value: &String) -> Self {
+    Answer::from(value.as_str())
+  }
+}
+
+impl From<char> for Answer {
+  fn from(value: char) -> Self {
+    Answer::Str(value.to_string())
+  }
+}
+
+macro_rules! answer_from {
+  ($variant:ident($inner:ty): $($t:ty),*) => {$(
+    impl From<$t> for Answer {
+      fn from(value: $t) -> Self {
+        Answer::$variant(value as $inner)
+      }
+    }
+
+    impl PartialEq<$t> for Answer {
+      fn eq(&self, other: &$t) -> bool {
+        match self {
+          Answer::$variant(v) => *v == *other as $inner,
+          _ => self.to_string() == other.to_string(),
+        }
+      }
+    }
+  )*};
+}
+
+answer_from!(Int(i64): i8, i16, i32, i64, isize);
+answer_from!(Uint(u64): u8, u16, u32, u64, usize);
+
+impl PartialEq<&str> for Answer {
+  fn eq(&self, other: &&str) -> bool {
+    match self {
+      Answer::Int(v) => other.trim().parse() == Ok(*v),
+      Answer::Uint(v) => other.trim().parse() == Ok(*v),
+      Answer::Str(v) => v == other.trim(),
+      Answer::MultiLine(v) => v == trim_lines(other),
+    }
+  }
+}
+
+/// The accepted answers for a day, as stored in `answers/{year}/d{day}.toml`,
+/// along with what is known about wrong guesses
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Answers {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub part1: Option<Answer>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub part2: Option<Answer>,
+  #[serde(default, skip_serializing_if = "Guesses::is_empty")]
+  pub guesses1: Guesses,
+  #[serde(default, skip_serializing_if = "Guesses::is_empty")]
+  pub guesses2: Guesses,
+}
+
+/// Rejected guesses for one part, and the bounds they put on the answer
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Guesses {
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub wrong: Vec<Answer>,
+  /// Highest guess that was too low
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub too_low: Option<i64>,
+  /// Lowest guess that was too high
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub too_high: Option<i64>,
+}
+
+/// Which way a wrong guess was off, when the site said so
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Hint {
+  TooLow,
+  TooHigh,
+  None,
+}
+
+impl Guesses {
+  pub fn is_empty(&self) -> bool {
+    self.wrong.is_empty() && self.too_low.is_none() && self.too_high.is_none()
+  }
+
+  pub fn add(&mut self, answer: &Answer, hint: Hint) {
+    if !self.wrong.contains(answer) {
+      self.wrong.push(answer.clone());
+    }
+    let Some(v) = answer.as_i64() else {
+      return;
+    };
+    match hint {
+      Hint::TooLow => self.too_low = Some(self.too_low.map_or(v, |b| b.max(v))),
+      Hint::TooHigh => self.too_high = Some(self.too_high.map_or(v, |b| b.min(v))),
+      Hint::None => (),
+    }
+  }
+
+  /// Why `answer` can't be right, if earlier guesses already rule it out
+  pub fn rule_out(&self, answer: &Answer) -> Option<String> {
+    if self.wrong.contains(answer) {
+      return Some(format!("{} was already guessed and was wrong", answer));
+    }
+    let v = answer.as_i64()?;
+    match (self.too_low, self.too_high) {
+      (Some(low), _) if v <= low => Some(format!("{} is not above {}, which was too low", v, low)),
+      (_, Some(high)) if v >= high => {
+        Some(format!("{} is not below {}, which was too high", v, high))
+      }
+      _ => None,
+    }
+  }
+}
+
+/// How a produced answer compares to the accepted one
+#[derive(Clone, Debug, PartialEq)]
+pub enum Verdict {
+  Pass,
+  Fail {
+    expected: Answer,
+  },
+  /// No answer has been accepted for this part yet
+  Unknown,
+}
+
+impl Answers {
+  pub fn get(&self, part: Part) -> Option<&Answer> {
+    match part {
+      Part::One => self.part1.as_ref(),
+      Part::Two => self.part2.as_ref(),
+    }
+  }
+
+  pub fn set(&mut self, part: Part, answer: &Answer) {
+    let answer = Some(answer.clone());
+    match part {
+      Part::One => self.part1 = answer,
+      Part::Two => self.part2 = answer,
+    }
+  }
+
+  pub fn guesses(&self, part: Part) -> &Guesses {
+    match part {
+      Part::One => &self.guesses1,
+      Part::Two => &self.guesses2,
+    }
+  }
+
+  pub fn guesses_mut(&mut self, part: Part) -> &mut Guesses {
+    match part {
+      Part::One => &mut self.guesses1,
+      Part::Two => &mut self.guesses2,
+    }
+  }
+
+  pub fn check(&self, part: Part, answer: &Answer) -> Verdict {
+    match self.get(part) {
+      None => Verdict::Unknown,
+      Some(expected) if expected == answer => Verdict::Pass,
+      Some(expected) => Verdict::Fail {
+        expected: expected.clone(),
+      },
+    }
+  }
+}
+
+/// A directory of `{year}/d{day}.toml` answer files
+pub struct AnswerStore {
+  root: PathBuf,
+}
+
+impl AnswerStore {
+  pub fn new(root: impl Into<PathBuf>) -> Self {
+    AnswerStore { root: root.into() }
+  }
+
+  pub fn path(&self, day: AocDay) -> PathBuf {
+    let AocDay::D(year, day) = day;
+    self
+      .root
+      .join(year.to_string())
+      .join(format!("d{}.toml", day))
+  }
+
+  /// Loads the day's answers, empty if none have been recorded yet
+  pub fn load(&self, day: AocDay) -> Result<Answers, Box<dyn Error>> {
+    let path = self.path(day);
+    match path.exists() {
+      true => Ok(toml::from_str(&fs::read_to_string(path)?)?),
+      false => Ok(Answers::default()),
+    }
+  }
+
+  pub fn save(&self, day: AocDay, answers: &Answers) -> Result<(), Box<dyn Error>> {
+    write_atomic(&self.path(day), &toml::to_string(answers)?)
+  }
+
+  /// Records a single part's accepted answer, keeping the other part as is.
+  /// Unless `force` is set, an answer known to be wrong or one that differs
+  /// from the accepted answer is refused rather than written.
+  pub fn record(
+    &self,
+    day: AocDay,
+    part: Part,
+    answer: &Answer,
+    force: bool,
+  ) -> Result<(), Box<dyn Error>> {
+    let mut answers = self.load(day)?;
+    if !force {
+      if let Some(reason) = answers.guesses(part).rule_out(answer) {
+        return Err(format!("Refusing to record {} {}: {}", day, part, reason).into());
+      }
+      match answers.get(part) {
+        Some(known) if known == answer => return Ok(()),
+        Some(known) => {
+          return Err(
+            format!(
+              "Refusing to record {} {}: {} is already accepted, not {}",
+              day, part, known, answer
+            )
+            .into(),
+          )
+        }
+        None => (),
+      }
+    }
+    answers.set(part, answer);
+    self.save(day, &answers)
+  }
+
+  /// Remembers a rejected guess so it is never submitted again
+  pub fn record_guess(
+    &self,
+    day: AocDay,
+    part: Part,
+    answer: &Answer,
+    hint: Hint,
+  ) -> Result<(), Box<dyn Error>> {
+    let mut answers = self.load(day)?;
+    answers.guesses_mut(part).add(answer, hint);
+    self.save(day, &answers)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn record_and_check() {
+    let root = std::env::temp_dir().join(format!("aoc-answers-{}", std::process::id()));
+    let store = AnswerStore::new(&root);
+    let day = AocDay::D(2022, 10);
+    assert_eq!(store.load(day).unwrap(), Answers::default());
+
+    store
+      .record(day, Part::One, &Answer::from("13140\n"), false)
+      .unwrap();
+    store
+      .record(day, Part::Two, &Answer::from("##..\n#..#"), false)
+      .unwrap();
+    assert!(store.path(day).ends_with("2022/d10.toml"));
+    assert!(store
+      .record(day, Part::One, &Answer::Int(13140), false)
+      .is_ok());
+    assert!(store
+      .record(day, Part::One, &Answer::Int(1), false)
+      .is_err());
+
+    let answers = store.load(day).unwrap();
+    fs::remove_dir_all(&root).unwrap();
+    assert_eq!(answers.check(Part::One, &Answer::Int(13140)), Verdict::Pass);
+    assert_eq!(
+      answers.check(Part::Two, &Answer::from("##..\n#..#")),
+      Verdict::Pass
+    );
+    assert_eq!(
+      answers.check(Part::One, &Answer::Uint(42)),
+      Verdict::Fail {
+        expected: Answer::Int(13140)
+      }
+    );
+    assert_eq!(
+      Answers::default().check(Part::One, &Answer::Int(42)),
+      Verdict::Unknown
+    );
+  }
+
+  #[test]
+  fn guesses() {
+    let root = std::env::temp_dir().join(format!("aoc-guesses-{}", std::process::id()));
+    let store = AnswerStore::new(&root);
+    let day = AocDay::D(2023, 5);
+    store
+      .record_guess(day, Part::Two, &Answer::from("100"), Hint::TooLow)
+      .unwrap();
+    store
+      .record_guess(day, Part::Two, &Answer::from("90"), Hint::TooLow)
+      .unwrap();
+    store
+      .record_guess(day, Part::Two, &Answer::from("500"), Hint::TooHigh)
+      .unwrap();
+    store
+      .record_guess(day, Part::Two, &Answer::from("abc"), Hint::None)
+      .unwrap();
+    assert!(store
+      .record(day, Part::Two, &Answer::from("abc"), false)
+      .is_err());
+    let answers = store.load(day).unwrap();
+    fs::remove_dir_all(&root).unwrap();
+
+    let g = answers.guesses(Part::Two);
+    assert_eq!(g.wrong, vec!["100", "90", "500", "abc"]);
+    assert_eq!((g.too_low, g.too_high), (Some(100), Some(500)));
+    assert!(g.rule_out(&Answer::from("abc")).is_some());
+    assert!(g.rule_out(&Answer::Int(100)).is_some());
+    assert!(g.rule_out(&Answer::Uint(42)).is_some());
+    assert!(g.rule_out(&Answer::from("500")).is_some());
+    assert!(g.rule_out(&Answer::Int(9000)).is_some());
+    assert!(g.rule_out(&Answer::Int(101)).is_none());
+    assert!(g.rule_out(&Answer::from("xyz")).is_none());
+    assert!(answers
+      .guesses(Part::One)
+      .rule_out(&Answer::Int(42))
+      .is_none());
+  }
+
+  #[test]
+  fn answers() {
+    assert_eq!(Answer::from(7u8), Answer::Uint(7));
+    assert_eq!(Answer::from(-7i32), Answer::Int(-7));
+    assert_eq!(Answer::Int(7), Answer::Uint(7));
+    assert_eq!(Answer::Int(7), Answer::from(" 7\n"));
+    assert_ne!(Answer::Int(-7), Answer::Uint(7));
+    assert_eq!(Answer::Uint(3), 3usize);
+    assert_eq!(Answer::from("abc"), "abc");
+    assert!(matches!(Answer::from("\n##\n.#\n"), Answer::MultiLine(s) if s == "##\n.#"));
+    assert!(matches!(Answer::from("  #\n# #\n"), Answer::MultiLine(s) if s == "  #\n# #"));
+    assert_eq!(Answer::from(" .#\n#.\n"), "\n .#\n#.");
+
+    let answers = Answers {
+      part1: Some(Answer::Uint(u64::MAX)),
+      part2: Some(Answer::from("a\nb")),
+      ..Answers::default()
+    };
+    let text = toml::to_string(&answers).unwrap();
+    assert_eq!(toml::from_str::<Answers>(&text).unwrap(), answers);
+  }
+}
@@ -0,0 +1,220 @@
+use std::{error::Error, time::Duration};
+
+use crate::{Answer, Part, Solver};
+
+pub struct BenchOpts {
+  /// Measured runs, each one a full parse + parts
+  pub runs: usize,
+  /// Runs made before measuring, whose timings are thrown away
+  pub warmup: usize,
+}
+
+impl Default for BenchOpts {
+  fn default() -> Self {
+    BenchOpts {
+      runs: 10,
+      warmup: 1,
+    }
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+  pub min: Duration,
+  pub median: Duration,
+  pub mean: Duration,
+  pub std_dev: Duration,
+}
+
+impl Stats {
+  /// Summarizes a set of samples, `None` if there are none
+  pub fn from_samples(samples: &[Duration]) -> Option<Stats> {
+    if samples.is_empty() {
+      return None;
+    }
+    let mut sorted = samples.to_vec();
+    sorted.sort();
+    let mid = sorted.len() / 2;
+    let median = match sorted.len() % 2 {
+      0 => (sorted[mid - 1] + sorted[mid]) / 2,
+      _ => sorted[mid],
+    };
+    let n = samples.len() as f64;
+    let mean = samples.iter().map(|d| d.as_secs_f64()).sum::<f64>() / n;
+    let var = samples
+      .iter()
+      .map(|d| (d.as_secs_f64() - mean).powi(2))
+      .sum::<f64>()
+      / n;
+    Some(Stats {
+      min: sorted[0],
+      median,
+      mean: Duration::from_secs_f64(mean),
+      std_dev: Duration::from_secs_f64(var.sqrt()),
+    })
+  }
+}
+
+pub struct BenchResult {
+  pub runs: usize,
+  pub parse: Stats,
+  pub parts: Vec<(Part, Stats)>,
+  /// What each part answered on the last run
+  pub answers: Vec<(Part, Answer)>,
+  /// Parts left out of the timings as they aren't solved yet
+  pub unsolved: Vec<Part>,
+}
+
+impl BenchResult {
+  /// The parts whose answer differs from `other`'s, as when a variant
+  /// disagrees with the day's own solution
+  pub fn mismatches(&self, other: &BenchResult) -> Vec<Part> {
+    self
+      .answers
+      .iter()
+      .filter(|(part, answer)| {
+        other
+          .answers
+          .iter()
+          .find(|(p, _)| p == part)
+          .is_some_and(|(_, a)| a != answer)
+      })
+      .map(|(part, _)| *part)
+      .collect()
+  }
+}
+
+/// Repeatedly parses `input` and solves the given parts, failing on the first
+/// run that errors since timings of a broken solution mean nothing. Parts
+/// that aren't solved are only run once, to find out that they aren't.
+pub fn bench(
+  solver: Solver,
+  input: &str,
+  parts: &[Part],
+  opts: &BenchOpts,
+) -> Result<BenchResult, Box<dyn Error>> {
+  if opts.runs == 0 {
+    return Err("Benchmark needs at least one run".into());
+  }
+  let mut parts = parts.to_vec();
+  let mut unsolved = Vec::new();
+  let mut parse = Vec::with_capacity(opts.runs);
+  let mut timings = vec![Vec::with_capacity(opts.runs); parts.len()];
+  let mut answers = Vec::new();
+  for run in 0..opts.warmup + opts.runs {
+    let mut res = solver(input.to_string(), &parts)?.into_result()?;
+    if run == 0 {
+      unsolved = res
+        .parts
+        .iter()
+        .filter(|p| p.is_unsolved())
+        .map(|p| p.part)
+        .collect();
+      res.parts.retain(|p| !p.is_unsolved());
+      parts.retain(|p| !unsolved.contains(p));
+    }
+    if run < opts.warmup {
+      continue;
+    }
+    parse.push(res.parse);
+    for (idx, p) in res.parts.iter().enumerate() {
+      timings[idx].push(p.elapsed);
+    }
+    answers = res
+      .parts
+      .into_iter()
+      .map(|p| (p.part, p.answer.unwrap()))
+      .collect();
+  }
+
+  Ok(BenchResult {
+    runs: opts.runs,
+    parse: Stats::from_samples(&parse).unwrap(),
+    parts: parts
+      .iter()
+      .zip(timings.iter())
+      .map(|(part, samples)| (*part, Stats::from_samples(samples).unwrap()))
+      .collect(),
+    answers,
+    unsolved,
+  })
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::Day;
+
+  fn ms(v: &[u64]) -> Vec<Duration> {
+    v.iter().map(|v| Duration::from_millis(*v)).collect()
+  }
+
+  #[test]
+  fn stats() {
+    let s = Stats::from_samples(&ms(&[4, 2, 8, 6])).unwrap();
+    assert_eq!(s.min, Duration::from_millis(2));
+    assert_eq!(s.median, Duration::from_millis(5));
+    assert_eq!(s.mean, Duration::from_millis(5));
+    assert_eq!(s.std_dev.as_micros(), 2236);
+
+    let s = Stats::from_samples(&ms(&[3, 1, 2])).unwrap();
+    assert_eq!(s.median, Duration::from_millis(2));
+    assert!(Stats::from_samples(&[]).is_none());
+  }
+
+  struct Half(i64);
+  impl TryFrom<String> for Half {
+    type Error = Box<dyn Error>;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+      Ok(Half(value.trim().parse()?))
+    }
+  }
+  impl Day for Half {
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+      Ok(Answer::from(self.0))
+    }
+
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+      Err(crate::Error::Unsolved.into())
+    }
+  }
+
+  #[test]
+  fn unsolved_parts_are_skipped() {
+    let opts = BenchOpts { runs: 3, warmup: 0 };
+    let res = bench(Half::run_input, "4", &Part::ALL, &opts).unwrap();
+    assert_eq!(res.parts.len(), 1);
+    assert_eq!(res.parts[0].0, Part::One);
+    assert_eq!(res.answers, [(Part::One, Answer::from(4))]);
+    assert_eq!(res.unsolved, [Part::Two]);
+
+    assert!(bench(Half::run_input, "four", &Part::ALL, &opts).is_err());
+  }
+
+  #[test]
+  fn mismatches() {
+    let result = |answers: &[(Part, i64)]| BenchResult {
+      runs: 1,
+      parse: Stats::from_samples(&ms(&[1])).unwrap(),
+      parts: vec![],
+      answers: answers
+        .iter()
+        .map(|(p, a)| (*p, Answer::from(*a)))
+        .collect(),
+      unsolved: vec![],
+    };
+    let base = result(&[(Part::One, 7), (Part::Two, 9)]);
+    assert!(result(&[(Part::One, 7), (Part::Two, 9)])
+      .mismatches(&base)
+      .is_empty());
+    assert_eq!(
+      result(&[(Part::One, 7), (Part::Two, 8)]).mismatches(&base),
+      [Part::Two]
+    );
+    // Parts only one side ran can't disagree
+    assert!(result(&[(Part::One, 7)])
+      .mismatches(&result(&[(Part::Two, 1)]))
+      .is_empty());
+  }
+}
@@ -0,0 +1,252 @@
+use std::{
+  env,
+  error::Error,
+  fmt::Display,
+  sync::Mutex,
+  thread,
+  time::{Duration, Instant},
+};
+
+use reqwest::{
+  blocking::{Client, RequestBuilder, Response},
+  header, StatusCode,
+};
+
+use crate::{AocDay, Part};
+
+pub const AOC_URL: &str = "https://adventofcode.com";
+
+/// Identifies this tool to the site, as the AoC FAQ asks automated tools to.
+/// `AOC_USER_AGENT` adds a way to contact whoever is running it.
+const REPO_URL: &str = "github.com/dfontana/adventofcode";
+
+/// Default minimum gap between any two requests made by this process
+const MIN_INTERVAL: Duration = Duration::from_secs(2);
+
+/// When the last request was sent, shared by every client in the process
+static LAST_REQUEST: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// Responses from the site that mean there's nothing worth caching
+#[derive(Debug, PartialEq)]
+pub enum ClientError {
+  /// The puzzle hasn't unlocked yet (or doesn't exist)
+  NotYetAvailable(AocDay),
+  /// The session cookie is missing or expired, so we got a login prompt
+  LoggedOut,
+}
+
+impl Display for ClientError {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    match self {
+      ClientError::NotYetAvailable(day) => write!(f, "{} is not available yet", day),
+      ClientError::LoggedOut => write!(f, "Not logged in, check that AOC_SESSION is current"),
+    }
+  }
+}
+
+impl Error for ClientError {}
+
+/// An authenticated session against adventofcode.com, or a stand-in for it
+pub struct AocClient {
+  base_url: String,
+  session: String,
+  http: Client,
+  user_agent: String,
+  min_interval: Duration,
+}
+
+impl AocClient {
+  pub fn new(base_url: &str, session: &str) -> Result<Self, Box<dyn Error>> {
+    Ok(AocClient {
+      base_url: base_url.trim_end_matches('/').to_string(),
+      session: session.to_string(),
+      http: Client::builder().gzip(true).build()?,
+      user_agent: REPO_URL.to_string(),
+      min_interval: MIN_INTERVAL,
+    })
+  }
+
+  /// Uses the `AOC_SESSION` cookie, `AOC_USER_AGENT` as the contact sent
+  /// along with each request, and `AOC_URL` if set to point somewhere other
+  /// than adventofcode.com
+  pub fn from_env() -> Result<Self, Box<dyn Error>> {
+    let session = env::var("AOC_SESSION").map_err(|_| "AOC_SESSION is not set")?;
+    let base_url = env::var("AOC_URL").unwrap_or_else(|_| AOC_URL.to_string());
+    let client = AocClient::new(&base_url, &session)?;
+    Ok(match env::var("AOC_USER_AGENT") {
+      Ok(contact) if !contact.trim().is_empty() => client.with_contact(contact.trim()),
+      _ => client,
+    })
+  }
+
+  /// Names who to contact about this tool's traffic in its user agent
+  pub fn with_contact(mut self, contact: &str) -> Self {
+    self.user_agent = format!("{} by {}", REPO_URL, contact);
+    self
+  }
+
+  pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+    self.min_interval = min_interval;
+    self
+  }
+
+  /// Sends the request once at least `min_interval` has passed since the last
+  fn send(&self, req: RequestBuilder) -> Result<Response, Box<dyn Error>> {
+    let mut last = LAST_REQUEST.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(wait) = last.and_then(|t| self.min_interval.checked_sub(t.elapsed())) {
+      thread::sleep(wait);
+    }
+    let res = req
+      .header(header::USER_AGENT, &self.user_agent)
+      .header(header::COOKIE, format!("session={}", self.session))
+      .send();
+    *last = Some(Instant::now());
+    Ok(res?)
+  }
+
+  /// Fetches the day's puzzle input
+  pub fn input(&self, day: AocDay) -> Result<String, Box<dyn Error>> {
+    let res = self.send(self.http.get(format!("{}/{}/input", self.base_url, day)))?;
+    let status = res.status();
+    let body = res.text()?;
+    match status {
+      StatusCode::NOT_FOUND => return Err(ClientError::NotYetAvailable(day).into()),
+      StatusCode::BAD_REQUEST | StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+        return Err(ClientError::LoggedOut.into())
+      }
+      // Error pages (rate limits, outages) say nothing about the session
+      s if !s.is_success() => {
+        return Err(format!("Downloading {} failed with {}", day, status).into())
+      }
+      _ => {}
+    }
+    if body.contains("before it unlocks") {
+      return Err(ClientError::NotYetAvailable(day).into());
+    }
+    if body.contains("Please log in") || looks_like_html(&body) {
+      return Err(ClientError::LoggedOut.into());
+    }
+    Ok(body)
+  }
+
+  /// Fetches the day's puzzle description page
+  pub fn puzzle(&self, day: AocDay) -> Result<String, Box<dyn Error>> {
+    let res = self.send(self.http.get(format!("{}/{}", self.base_url, day)))?;
+    if res.status() == StatusCode::NOT_FOUND {
+      return Err(ClientError::NotYetAvailable(day).into());
+    }
+    Ok(res.error_for_status()?.text()?)
+  }
+
+  /// Posts an answer, returning the raw HTML of the response page
+  pub fn post_answer(
+    &self,
+    day: AocDay,
+    part: Part,
+    answer: &str,
+  ) -> Result<String, Box<dyn Error>> {
+    let level = match part {
+      Part::One => "1",
+      Part::Two => "2",
+    };
+    let body = self
+      .send(
+        self
+          .http
+          .post(format!("{}/{}/answer", self.base_url, day))
+          .form(&[("level", level), ("answer", answer)]),
+      )?
+      .error_for_status()?
+      .text()?;
+    Ok(body)
+  }
+}
+
+/// Inputs are plain text, so markup means we were served a page instead
+pub fn looks_like_html(body: &str) -> bool {
+  let start = body.trim_start().to_lowercase();
+  start.starts_with("<!doctype html") || start.starts_with("<html")
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::stand_in;
+
+  fn client(url: &str) -> AocClient {
+    AocClient::new(url, "s3cr3t")
+      .unwrap()
+      .with_min_interval(Duration::ZERO)
+  }
+
+  #[test]
+  fn input() {
+    let day = AocDay::D(2024, 3);
+    let (url, req) = stand_in::serve(200, "1 2\n3 4\n".into());
+    assert_eq!(client(&url).input(day).unwrap(), "1 2\n3 4\n");
+    let req = req.recv().unwrap();
+    assert!(req.starts_with("GET /2024/day/3/input "));
+    assert!(req.contains(&format!("user-agent: {}\r\n", REPO_URL)));
+
+    let (url, req) = stand_in::serve(200, "1\n".into());
+    client(&url).with_contact("someone").input(day).unwrap();
+    let req = req.recv().unwrap();
+    assert!(req.contains(&format!("user-agent: {} by someone\r\n", REPO_URL)));
+
+    let (url, _) = stand_in::serve(
+      404,
+      "Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available.".into(),
+    );
+    let err = client(&url).input(day).unwrap_err();
+    assert_eq!(
+      err.downcast_ref::<ClientError>(),
+      Some(&ClientError::NotYetAvailable(day))
+    );
+
+    let (url, _) = stand_in::serve(
+      400,
+      "Puzzle inputs differ by user.  Please log in to get your puzzle input.".into(),
+    );
+    let err = client(&url).input(day).unwrap_err();
+    assert_eq!(
+      err.downcast_ref::<ClientError>(),
+      Some(&ClientError::LoggedOut)
+    );
+
+    let (url, _) = stand_in::serve(
+      200,
+      "<!DOCTYPE html>\n<html><body>[Log In]</body></html>".into(),
+    );
+    let err = client(&url).input(day).unwrap_err();
+    assert_eq!(
+      err.downcast_ref::<ClientError>(),
+      Some(&ClientError::LoggedOut)
+    );
+
+    // An error page isn't a login prompt, the session may well be fine
+    let (url, _) = stand_in::serve(
+      500,
+      "<!DOCTYPE html>\n<html><body>Internal Server Error</body></html>".into(),
+    );
+    let err = client(&url).input(day).unwrap_err();
+    assert_eq!(err.downcast_ref::<ClientError>(), None);
+    assert!(err.to_string().contains("500"));
+  }
+
+  #[test]
+  fn throttles() {
+    let (url, _) = stand_in::serve(200, "1".into());
+    let (url2, _) = stand_in::serve(200, "2".into());
+    let interval = Duration::from_millis(300);
+    let now = Instant::now();
+    client(&url)
+      .with_min_interval(interval)
+      .input(AocDay::D(2024, 1))
+      .unwrap();
+    client(&url2)
+      .with_min_interval(interval)
+      .input(AocDay::D(2024, 1))
+      .unwrap();
+    assert!(now.elapsed() >= interval);
+  }
+}
@@ -0,0 +1,133 @@
+use std::{error::Error as StdError, fmt::Display, path::PathBuf};
+
+use crate::{AocDay, Part};
+
+/// Everything that can go wrong getting a day's answers, so callers can tell
+/// a missing input from a broken solution without matching on messages
+#[derive(Debug)]
+pub enum Error {
+  /// Outside 1..=25, or no solution has been written for it
+  UnknownDay(AocDay),
+  /// The input isn't cached and there's no `AOC_SESSION` to download it with
+  InputMissing(PathBuf),
+  /// The cached input fails `check_input` and there's no `AOC_SESSION` to
+  /// download it again
+  InputInvalid { path: PathBuf, reason: String },
+  /// The cached input is there but can't be read, eg it isn't UTF-8
+  InputUnreadable {
+    path: PathBuf,
+    source: std::io::Error,
+  },
+  DownloadFailed {
+    day: AocDay,
+    source: Box<dyn StdError>,
+  },
+  /// The input could not be parsed, at the given 1-based line and column
+  /// when the parser knows them, see `parse::Line`
+  ParseFailed {
+    line: Option<usize>,
+    column: Option<usize>,
+    /// The offending line
+    text: Option<String>,
+    reason: String,
+  },
+  SolverFailed {
+    part: Part,
+    source: Box<dyn StdError>,
+  },
+  /// Returned by a part in place of an answer when it hasn't been solved,
+  /// so runs can show it as such without failing
+  Unsolved,
+}
+
+impl Error {
+  /// Wraps whatever a parser returned, keeping it as is if it already says
+  /// where parsing failed
+  pub fn parse(e: Box<dyn StdError>) -> Self {
+    match e.downcast::<Error>() {
+      Ok(e) => *e,
+      Err(e) => Error::ParseFailed {
+        line: None,
+        column: None,
+        text: None,
+        reason: e.to_string(),
+      },
+    }
+  }
+}
+
+impl Display for Error {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    match self {
+      Error::UnknownDay(day) => write!(f, "Unknown day {}", day),
+      Error::InputMissing(path) => write!(
+        f,
+        "No input at {} and AOC_SESSION is not set to download it",
+        path.display()
+      ),
+      Error::InputInvalid { path, reason } => write!(
+        f,
+        "Cached input at {} is invalid ({}) and AOC_SESSION is not set to re-download it",
+        path.display(),
+        reason
+      ),
+      Error::InputUnreadable { path, source } => {
+        write!(f, "Reading input {} failed: {}", path.display(), source)
+      }
+      Error::DownloadFailed { day, source } => write!(f, "Downloading {} failed: {}", day, source),
+      Error::ParseFailed {
+        line,
+        column,
+        text,
+        reason,
+      } => {
+        write!(f, "Parse failed")?;
+        match (line, column) {
+          (Some(l), Some(c)) => write!(f, " at line {}, column {}", l, c)?,
+          (Some(l), None) => write!(f, " at line {}", l)?,
+          _ => (),
+        }
+        if let Some(text) = text {
+          write!(f, " (`{}`)", text)?;
+        }
+        write!(f, ": {}", reason)
+      }
+      Error::SolverFailed { part, source } => write!(f, "{} failed: {}", part, source),
+      Error::Unsolved => write!(f, "Not solved"),
+    }
+  }
+}
+
+impl StdError for Error {
+  fn source(&self) -> Option<&(dyn StdError + 'static)> {
+    match self {
+      Error::DownloadFailed { source, .. } | Error::SolverFailed { source, .. } => {
+        Some(source.as_ref())
+      }
+      Error::InputUnreadable { source, .. } => Some(source),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn parse_errors() {
+    let e = Error::parse("invalid digit found in string".into());
+    assert_eq!(e.to_string(), "Parse failed: invalid digit found in string");
+
+    let located: Box<dyn StdError> = Box::new(Error::ParseFailed {
+      line: Some(3),
+      column: None,
+      text: Some("1 2".into()),
+      reason: "expected ','".into(),
+    });
+    assert_eq!(
+      Error::parse(located).to_string(),
+      "Parse failed at line 3 (`1 2`): expected ','"
+    );
+  }
+}
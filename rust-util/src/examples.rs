@@ -0,0 +1,105 @@
+use std::{error::Error, fs, path::PathBuf};
+
+use regex::Regex;
+
+use crate::{client::AocClient, input_path, write_atomic, AocDay};
+
+/// Where the day's `n`th example is cached, ie `{input_dir}/{year}/d{day}.example{n}`
+pub fn example_path(day: AocDay, n: usize) -> Result<PathBuf, Box<dyn Error>> {
+  let mut path = input_path(day)?.into_os_string();
+  path.push(format!(".example{}", n));
+  Ok(path.into())
+}
+
+/// Pulls the text of every `<pre><code>` block out of a puzzle page, in order
+pub fn extract_examples(html: &str) -> Result<Vec<String>, Box<dyn Error>> {
+  let blocks = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>")?;
+  let tags = Regex::new(r"<[^>]*>")?;
+  Ok(
+    blocks
+      .captures_iter(html)
+      .map(|c| unescape(&tags.replace_all(&c[1], "")))
+      .collect(),
+  )
+}
+
+fn unescape(s: &str) -> String {
+  s.replace("&lt;", "<")
+    .replace("&gt;", ">")
+    .replace("&quot;", "\"")
+    .replace("&#39;", "'")
+    .replace("&amp;", "&")
+}
+
+/// Downloads the puzzle page and caches each of its examples, returning
+/// where they were written
+pub fn fetch_examples(client: &AocClient, day: AocDay) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+  let examples = extract_examples(&client.puzzle(day)?)?;
+  if examples.is_empty() {
+    return Err(format!("No examples found for {}", day).into());
+  }
+  examples
+    .iter()
+    .enumerate()
+    .map(|(idx, example)| {
+      let path = example_path(day, idx + 1)?;
+      write_atomic(&path, example)?;
+      Ok(path)
+    })
+    .collect()
+}
+
+/// Reads the day's `n`th example (counting from 1), downloading the puzzle
+/// page when it hasn't been cached yet
+pub fn read_example(day: AocDay, n: usize) -> Result<String, Box<dyn Error>> {
+  let path = example_path(day, n)?;
+  if !path.exists() {
+    println!("Attempting example download...");
+    // Tests run outside the runner, so pick up the session from `.env` here too
+    dotenv::dotenv().ok();
+    fetch_examples(&AocClient::from_env()?, day)?;
+  }
+  fs::read_to_string(&path)
+    .map_err(|e| format!("Example {} for {} unavailable: {}", n, day, e).into())
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::stand_in;
+  use std::time::Duration;
+
+  const FIXTURE: &str = include_str!("fixtures/puzzle.html");
+
+  #[test]
+  fn extracts_examples() {
+    assert_eq!(
+      extract_examples(FIXTURE).unwrap(),
+      vec![
+        "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)\n+mul(32,64](mul(11,8)undo()?mul(8,5))\n",
+        "a < b\nc > d \"e\" 'f'\n",
+        "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))\n",
+      ]
+    );
+    assert!(extract_examples("<p>no code here</p>").unwrap().is_empty());
+  }
+
+  #[test]
+  fn example_layout() {
+    assert!(example_path(AocDay::D(2024, 3), 2)
+      .unwrap()
+      .ends_with("2024/d3.example2"));
+    assert!(example_path(AocDay::D(2024, 26), 1).is_err());
+  }
+
+  #[test]
+  fn puzzle_page() {
+    let (url, req) = stand_in::serve(200, FIXTURE.to_string());
+    let client = AocClient::new(&url, "s3cr3t")
+      .unwrap()
+      .with_min_interval(Duration::ZERO);
+    let page = client.puzzle(AocDay::D(2024, 3)).unwrap();
+    assert!(req.recv().unwrap().starts_with("GET /2024/day/3 "));
+    assert_eq!(extract_examples(&page).unwrap().len(), 3);
+  }
+}
@@ -1,8 +1,11 @@
 use std::{fmt::Display, io::Stdout, io::Write};
 
+use crate::{parse, point::Point, Error};
+
+/// A rectangular board, stored row-major in a single `Vec` so `board[y * x_max + x]` is `(y, x)`
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Grid<T> {
-  board: Vec<Vec<T>>,
+  board: Vec<T>,
   x_max: usize,
   y_max: usize,
 }
@@ -22,6 +25,32 @@ pub enum Dir {
 }
 
 impl Dir {
+  /// The four directions along the axes
+  pub const CARDINAL: [Dir; 4] = [Dir::N, Dir::E, Dir::S, Dir::W];
+  /// Every direction to a neighbouring tile, diagonals included
+  pub const ALL: [Dir; 8] = [
+    Dir::N,
+    Dir::E,
+    Dir::S,
+    Dir::W,
+    Dir::NE,
+    Dir::SE,
+    Dir::SW,
+    Dir::NW,
+  ];
+
+  /// Where the direction sits in `CARDINAL`, for keeping something per
+  /// direction in a `[T; 4]`. `None` for diagonals and `Idle`
+  pub fn index(&self) -> Option<usize> {
+    match self {
+      Dir::N => Some(0),
+      Dir::E => Some(1),
+      Dir::S => Some(2),
+      Dir::W => Some(3),
+      _ => None,
+    }
+  }
+
   pub fn rev(&self) -> Dir {
     match self {
       Dir::N => Dir::S,
@@ -39,17 +68,17 @@ impl Dir {
 
 impl<T: Display> Grid<T> {
   pub fn print(&self) {
-    for y in 0..self.y_max {
-      for x in 0..self.x_max {
-        print!("{}", self.board[y][x]);
+    for row in self.rows() {
+      for t in row {
+        print!("{}", t);
       }
       println!();
     }
   }
   pub fn write(&self, stdout: &mut Stdout) {
-    for y in 0..self.y_max {
-      for x in 0..self.x_max {
-        write!(stdout, "{}", self.board[y][x]).unwrap();
+    for row in self.rows() {
+      for t in row {
+        write!(stdout, "{}", t).unwrap();
       }
       writeln!(stdout).unwrap();
     }
@@ -57,52 +86,116 @@ impl<T: Display> Grid<T> {
 }
 
 impl<T: Clone> Grid<T> {
+  /// A `height` by `width` board with every tile set to `val`
+  pub fn filled(height: usize, width: usize, val: T) -> Self {
+    Grid {
+      board: vec![val; height * width],
+      x_max: width,
+      y_max: height,
+    }
+  }
+
+  /// Rotates the board clockwise, so the left column becomes the top row
   pub fn rot90(&mut self) -> &mut Self {
-    self.board = (0..self.board[0].len())
-      .map(|i| {
-        self
-          .board
-          .iter()
-          .map(|inner| inner[i].clone())
-          .rev()
-          .collect::<Vec<_>>()
-      })
-      .collect();
+    let (y_max, x_max) = (self.y_max, self.x_max);
+    let mut board = Vec::with_capacity(self.board.len());
+    for x in 0..x_max {
+      for y in (0..y_max).rev() {
+        board.push(self.board[y * x_max + x].clone());
+      }
+    }
+    self.board = board;
+    (self.y_max, self.x_max) = (x_max, y_max);
     self
   }
 }
 
 impl<T: From<char>> Grid<T> {
+  /// Panics on ragged or empty input, see `try_new_from`
   pub fn new_from(inp: String) -> Self {
     Grid::new_from_map(inp, |c| T::from(c))
   }
+
+  /// A tile per character of each line of `inp`
+  pub fn try_new_from(inp: String) -> Result<Self, Error> {
+    Grid::try_new_from_map(inp, |c| T::from(c))
+  }
 }
 
 impl<T> Grid<T> {
+  /// Panics on ragged or empty input, see `try_new`
   pub fn new(board: Vec<Vec<T>>) -> Self {
-    Grid {
-      x_max: board[0].len(),
-      y_max: board.len(),
-      board,
+    Grid::try_new(board).unwrap_or_else(|e| panic!("{}", e))
+  }
+
+  /// A board from its rows, which must all be as wide as the first
+  pub fn try_new(board: Vec<Vec<T>>) -> Result<Self, Error> {
+    let x_max = board.first().map_or(0, |row| row.len());
+    if x_max == 0 {
+      return Err(empty());
     }
+    if let Some(idx) = board.iter().position(|row| row.len() != x_max) {
+      return Err(Error::ParseFailed {
+        line: Some(idx + 1),
+        column: None,
+        text: None,
+        reason: ragged(board[idx].len(), x_max),
+      });
+    }
+    let y_max = board.len();
+    Ok(Grid {
+      board: board.into_iter().flatten().collect(),
+      x_max,
+      y_max,
+    })
   }
 
+  /// Panics on ragged or empty input, see `try_new_from_map`
   pub fn new_from_map(inp: String, fr: impl Fn(char) -> T) -> Self {
-    let board: Vec<Vec<T>> = inp
-      .lines()
-      .map(|l| l.chars().map(|c| fr(c)).collect())
-      .collect();
-    Grid {
-      x_max: board[0].len(),
-      y_max: board.len(),
-      board,
+    Grid::try_new_from_map(inp, fr).unwrap_or_else(|e| panic!("{}", e))
+  }
+
+  /// A tile per character of each line of `inp`, converted by `fr`. Every line
+  /// must be as wide as the first.
+  pub fn try_new_from_map(inp: String, fr: impl Fn(char) -> T) -> Result<Self, Error> {
+    let x_max = inp.lines().next().map_or(0, |l| l.chars().count());
+    if x_max == 0 {
+      return Err(empty());
     }
+    let mut board = Vec::with_capacity(inp.len());
+    let mut y_max = 0;
+    for line in parse::lines(&inp) {
+      let width = line.text.chars().count();
+      if width != x_max {
+        return Err(line.error(ragged(width, x_max)));
+      }
+      board.extend(line.text.chars().map(&fr));
+      y_max += 1;
+    }
+    Ok(Grid {
+      board,
+      x_max,
+      y_max,
+    })
   }
 
   pub fn height(&self) -> usize {
     self.y_max
   }
 
+  pub fn width(&self) -> usize {
+    self.x_max
+  }
+
+  /// Each row of the board, top to bottom
+  pub fn rows(&self) -> std::slice::Chunks<'_, T> {
+    self.board.chunks(self.x_max.max(1))
+  }
+
+  fn idx(&self, y: usize, x: usize) -> usize {
+    y * self.x_max + x
+  }
+
   pub fn bottom_right(&self) -> (usize, usize) {
     (self.y_max - 1, self.x_max - 1)
   }
@@ -147,7 +240,17 @@ impl<T> Grid<T> {
 
   pub fn at(&self, y: usize, x: usize) -> Option<&T> {
     match self.in_bounds(y, x) {
-      true => Some(&self.board[y][x]),
+      true => Some(&self.board[self.idx(y, x)]),
+      false => None,
+    }
+  }
+
+  pub fn at_mut(&mut self, y: usize, x: usize) -> Option<&mut T> {
+    match self.in_bounds(y, x) {
+      true => {
+        let idx = self.idx(y, x);
+        Some(&mut self.board[idx])
+      }
       false => None,
     }
   }
@@ -186,42 +289,170 @@ impl<T> Grid<T> {
       .and_then(|loc| self.at(loc.0, loc.1).map(|t| (loc, t)))
   }
 
+  pub fn at_point(&self, p: Point) -> Option<&T> {
+    p.yx().and_then(|(y, x)| self.at(y, x))
+  }
+
+  /// Like `step`, but from and to a `Point`
+  pub fn step_point(&self, p: Point, step: usize, dir: &Dir) -> Option<Point> {
+    let (y, x) = p.yx().filter(|(y, x)| self.in_bounds(*y, *x))?;
+    self
+      .step(y, x, step, dir)
+      .map(|(y, x)| Point::from_yx(y, x))
+  }
+
   pub fn put(&mut self, y: usize, x: usize, val: T) {
-    self.board[y][x] = val;
+    assert!(self.in_bounds(y, x), "({}, {}) is outside the grid", y, x);
+    let idx = self.idx(y, x);
+    self.board[idx] = val;
   }
 
   /// Iterate through the grid in (y, x, T) pairs
-  pub fn iter<'a>(&'a self) -> GridIter<'a, T> {
+  pub fn iter(&self) -> GridIter<'_, T> {
     GridIter {
-      grid: self,
-      loc: (0, 0),
+      inner: self.board.iter().enumerate(),
+      x_max: self.x_max,
     }
   }
+
+  /// The in-bounds tiles next to `(y, x)` in each of `dirs`, as (loc, Dir, T)
+  pub fn neighbors_with(
+    &self,
+    y: usize,
+    x: usize,
+    dirs: impl IntoIterator<Item = Dir>,
+  ) -> impl Iterator<Item = ((usize, usize), Dir, &T)> {
+    dirs
+      .into_iter()
+      .filter_map(move |d| self.at_step(y, x, 1, &d).map(|(loc, t)| (loc, d, t)))
+  }
+
+  /// The tiles north, east, south and west of `(y, x)`
+  pub fn neighbors4(&self, y: usize, x: usize) -> impl Iterator<Item = ((usize, usize), Dir, &T)> {
+    self.neighbors_with(y, x, Dir::CARDINAL)
+  }
+
+  /// The tiles around `(y, x)`, diagonals included
+  pub fn neighbors8(&self, y: usize, x: usize) -> impl Iterator<Item = ((usize, usize), Dir, &T)> {
+    self.neighbors_with(y, x, Dir::ALL)
+  }
+
+  /// Mutable `neighbors_with`. Tiles come in row-major order rather than the
+  /// order of `dirs`, and a tile reached by more than one of `dirs` comes once.
+  pub fn neighbors_with_mut(
+    &mut self,
+    y: usize,
+    x: usize,
+    dirs: impl IntoIterator<Item = Dir>,
+  ) -> impl Iterator<Item = ((usize, usize), Dir, &mut T)> {
+    let mut locs: Vec<_> = dirs
+      .into_iter()
+      .filter_map(|d| self.step(y, x, 1, &d).map(|loc| (loc, d)))
+      .filter(|((y, x), _)| self.in_bounds(*y, *x))
+      .collect();
+    locs.sort_by_key(|(loc, _)| *loc);
+    locs.dedup_by_key(|(loc, _)| *loc);
+
+    // Hand out each tile by splitting it off the front of what's left
+    let x_max = self.x_max;
+    let mut rest = self.board.as_mut_slice();
+    let mut start = 0;
+    locs.into_iter().map(move |((y, x), d)| {
+      let idx = y * x_max + x;
+      let (_, tail) = std::mem::take(&mut rest).split_at_mut(idx - start);
+      let (t, tail) = tail.split_first_mut().unwrap();
+      rest = tail;
+      start = idx + 1;
+      ((y, x), d, t)
+    })
+  }
+
+  pub fn neighbors4_mut(
+    &mut self,
+    y: usize,
+    x: usize,
+  ) -> impl Iterator<Item = ((usize, usize), Dir, &mut T)> {
+    self.neighbors_with_mut(y, x, Dir::CARDINAL)
+  }
+
+  pub fn neighbors8_mut(
+    &mut self,
+    y: usize,
+    x: usize,
+  ) -> impl Iterator<Item = ((usize, usize), Dir, &mut T)> {
+    self.neighbors_with_mut(y, x, Dir::ALL)
+  }
+
+  /// Iterate through the grid in (Point, T) pairs
+  pub fn iter_points(&self) -> impl Iterator<Item = (Point, &T)> {
+    self.iter().map(|(y, x, t)| (Point::from_yx(y, x), t))
+  }
 }
 
-pub struct GridIter<'a, T> {
+/// A grid repeated endlessly in every direction, see `Grid::wrapping`
+#[derive(Clone, Copy, Debug)]
+pub struct Wrapping<'a, T> {
   grid: &'a Grid<T>,
-  loc: (usize, usize),
+}
+
+impl<T> Grid<T> {
+  /// Views the grid as tiling the plane, so any `Point` lands on one of its
+  /// tiles
+  pub fn wrapping(&self) -> Wrapping<'_, T> {
+    Wrapping { grid: self }
+  }
+}
+
+impl<'a, T> Wrapping<'a, T> {
+  fn size(&self) -> Point {
+    Point::new(self.grid.x_max as i64, self.grid.y_max as i64)
+  }
+
+  /// Where `p` falls on the stored grid
+  pub fn wrap(&self, p: Point) -> Point {
+    p.rem_euclid(self.size())
+  }
+
+  /// Which copy of the grid `p` falls in, the stored one being `(0, 0)` and
+  /// the one to its right `(1, 0)`
+  pub fn tile(&self, p: Point) -> Point {
+    p.div_euclid(self.size())
+  }
+
+  pub fn at(&self, p: Point) -> &'a T {
+    let (y, x) = self.wrap(p).yx().unwrap();
+    &self.grid.board[self.grid.idx(y, x)]
+  }
+
+  /// Like `Grid::step`, but never out of bounds. The result isn't wrapped, so
+  /// `wrap` and `tile` still say where it is on the stored grid and which copy.
+  pub fn step(&self, p: Point, step: i64, dir: &Dir) -> Point {
+    p.step(dir, step)
+  }
+
+  /// The unwrapped point `step` tiles away in `dir`, with its tile
+  pub fn at_step(&self, p: Point, step: i64, dir: &Dir) -> (Point, &'a T) {
+    let p = self.step(p, step, dir);
+    (p, self.at(p))
+  }
+}
+
+pub struct GridIter<'a, T> {
+  inner: std::iter::Enumerate<std::slice::Iter<'a, T>>,
+  x_max: usize,
 }
 impl<'a, T> Iterator for GridIter<'a, T> {
   type Item = (usize, usize, &'a T);
 
   fn next(&mut self) -> Option<Self::Item> {
-    match self.grid.at(self.loc.0, self.loc.1) {
-      Some(t) => {
-        let ret = Some((self.loc.0, self.loc.1, t));
-        self.loc = (self.loc.0, self.loc.1 + 1);
-        return ret;
-      }
-      None => match self.grid.at(self.loc.0 + 1, 0) {
-        Some(t) => {
-          let ret = Some((self.loc.0 + 1, 0, t));
-          self.loc = (self.loc.0 + 1, 1);
-          return ret;
-        }
-        None => None,
-      },
-    }
+    self
+      .inner
+      .next()
+      .map(|(i, t)| (i / self.x_max, i % self.x_max, t))
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    self.inner.size_hint()
   }
 }
 
@@ -244,3 +475,169 @@ impl Iterator for RangeIter {
     }
   }
 }
+
+fn empty() -> Error {
+  Error::ParseFailed {
+    line: None,
+    column: None,
+    text: None,
+    reason: "Grid has no tiles".to_string(),
+  }
+}
+
+fn ragged(width: usize, expected: usize) -> String {
+  format!("Grid row is {} wide, expected {}", width, expected)
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn grid() -> Grid<char> {
+    Grid::new_from("abc\ndef\n".to_string())
+  }
+
+  #[test]
+  fn flat_indexing() {
+    let mut g = grid();
+    assert_eq!((g.height(), g.width()), (2, 3));
+    assert_eq!(g.at(1, 0), Some(&'d'));
+    assert_eq!(g.at(0, 3), None);
+    assert_eq!(g.at_step(0, 2, 1, &Dir::S), Some(((1, 2), &'f')));
+    g.put(1, 2, 'z');
+    *g.at_mut(0, 0).unwrap() = 'y';
+    assert_eq!(g.at_mut(2, 0), None);
+    assert_eq!(g, Grid::new(vec![vec!['y', 'b', 'c'], vec!['d', 'e', 'z']]));
+  }
+
+  #[test]
+  fn ragged_or_empty() {
+    let Err(Error::ParseFailed {
+      line, text, reason, ..
+    }) = Grid::<char>::try_new_from("abc\nde\nfgh\n".to_string())
+    else {
+      panic!("ragged input parsed");
+    };
+    assert_eq!((line, text.as_deref()), (Some(2), Some("de")));
+    assert_eq!(reason, "Grid row is 2 wide, expected 3");
+    let Err(Error::ParseFailed { line, .. }) = Grid::try_new(vec![vec![1, 2], vec![3]]) else {
+      panic!("ragged rows made a grid");
+    };
+    assert_eq!(line, Some(2));
+    assert!(Grid::<char>::try_new_from("".to_string()).is_err());
+    assert!(Grid::<char>::try_new_from("\nabc".to_string()).is_err());
+    assert!(Grid::<u8>::try_new(vec![]).is_err());
+    assert!(Grid::<u8>::try_new(vec![vec![]]).is_err());
+    assert_eq!(
+      Grid::try_new_from("abc\ndef\n".to_string()).unwrap(),
+      grid()
+    );
+  }
+
+  #[test]
+  fn dir_index() {
+    for (i, d) in Dir::CARDINAL.iter().enumerate() {
+      assert_eq!(d.index(), Some(i));
+    }
+    assert_eq!(Dir::NE.index(), None);
+    assert_eq!(Dir::Idle.index(), None);
+  }
+
+  #[test]
+  fn iter_row_major() {
+    let locs: Vec<_> = grid().iter().map(|(y, x, c)| (y, x, *c)).collect();
+    assert_eq!(locs.len(), 6);
+    assert_eq!(locs[2], (0, 2, 'c'));
+    assert_eq!(locs[3], (1, 0, 'd'));
+  }
+
+  #[test]
+  fn points() {
+    let g = grid();
+    assert_eq!(g.at_point(Point::new(2, 1)), Some(&'f'));
+    assert_eq!(g.at_point(Point::new(-1, 0)), None);
+    assert_eq!(
+      g.step_point(Point::new(2, 0), 1, &Dir::SW),
+      Some(Point::new(1, 1))
+    );
+    assert_eq!(g.step_point(Point::new(2, 0), 1, &Dir::E), None);
+    assert_eq!(g.step_point(Point::new(5, 0), 1, &Dir::W), None);
+    let (p, c) = g.iter_points().nth(4).unwrap();
+    assert_eq!((p, *c), (Point::new(1, 1), 'e'));
+  }
+
+  #[test]
+  fn neighbors() {
+    let g = grid();
+    let n: Vec<_> = g.neighbors4(0, 1).map(|(l, d, c)| (l, d, *c)).collect();
+    assert_eq!(
+      n,
+      [
+        ((0, 2), Dir::E, 'c'),
+        ((1, 1), Dir::S, 'e'),
+        ((0, 0), Dir::W, 'a')
+      ]
+    );
+    assert_eq!(g.neighbors8(0, 1).count(), 5);
+    assert_eq!(g.neighbors8(1, 1).count(), 5);
+    let diag: Vec<_> = g.neighbors_with(1, 0, [Dir::NE, Dir::SE]).collect();
+    assert_eq!(diag, [((0, 1), Dir::NE, &'b')]);
+  }
+
+  #[test]
+  fn neighbors_mut() {
+    let mut g = grid();
+    for (_, _, c) in g.neighbors8_mut(1, 1) {
+      *c = c.to_ascii_uppercase();
+    }
+    assert_eq!(
+      g,
+      Grid::new_from(
+        "ABC
+DeF
+"
+        .to_string()
+      )
+    );
+    // Repeated or idle directions don't hand out the same tile twice
+    let locs: Vec<_> = g
+      .neighbors_with_mut(0, 0, [Dir::S, Dir::Idle, Dir::S, Dir::E])
+      .map(|(l, d, _)| (l, d))
+      .collect();
+    assert_eq!(
+      locs,
+      [((0, 0), Dir::Idle), ((0, 1), Dir::E), ((1, 0), Dir::S)]
+    );
+  }
+
+  #[test]
+  fn wrapping() {
+    let g = grid();
+    let w = g.wrapping();
+    assert_eq!(w.at(Point::new(4, 3)), &'e');
+    assert_eq!(w.at(Point::new(-1, -1)), &'f');
+    assert_eq!(w.wrap(Point::new(-4, 5)), Point::new(2, 1));
+    assert_eq!(w.tile(Point::new(-4, 5)), Point::new(-2, 2));
+    assert_eq!(w.tile(Point::new(2, 1)), Point::ORIGIN);
+    let p = w.step(Point::new(0, 0), 1, &Dir::NW);
+    assert_eq!(p, Point::new(-1, -1));
+    assert_eq!(
+      (w.wrap(p), w.tile(p)),
+      (Point::new(2, 1), Point::new(-1, -1))
+    );
+    let (p, t) = w.at_step(Point::new(2, 1), 2, &Dir::E);
+    assert_eq!((p, t), (Point::new(4, 1), &'e'));
+    assert_eq!(w.tile(p), Point::new(1, 0));
+  }
+
+  #[test]
+  fn rot90_non_square() {
+    let mut g = grid();
+    g.rot90();
+    assert_eq!((g.height(), g.width()), (3, 2));
+    let rows: Vec<String> = g.rows().map(|r| r.iter().collect()).collect();
+    assert_eq!(rows, ["da", "eb", "fc"]);
+    g.rot90().rot90().rot90();
+    assert_eq!(g, grid());
+  }
+}
@@ -0,0 +1,275 @@
+use std::{
+  error::Error,
+  fs,
+  path::Path,
+  process::Command,
+  time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+  bench::{BenchResult, Stats},
+  write_atomic, AocDay, Part,
+};
+
+/// A single phase (parse, part 1 or part 2) of one benchmark run
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+  pub year: usize,
+  pub day: usize,
+  /// One of `parse`, `1` or `2`
+  pub phase: String,
+  pub commit: String,
+  /// Seconds since the unix epoch
+  pub recorded: u64,
+  pub runs: usize,
+  pub min_ns: u64,
+  pub median_ns: u64,
+  pub mean_ns: u64,
+  pub std_dev_ns: u64,
+  /// Slower than its baseline when recorded, so never used as one itself
+  #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+  pub regressed: bool,
+}
+
+impl Entry {
+  pub fn median(&self) -> Duration {
+    Duration::from_nanos(self.median_ns)
+  }
+}
+
+/// Every benchmark ever recorded, oldest first
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct History {
+  pub entries: Vec<Entry>,
+}
+
+/// How a phase's median compares to the same phase in the baseline
+pub struct Comparison {
+  pub phase: String,
+  pub baseline: Entry,
+  pub median: Duration,
+  /// Relative change, eg `0.25` is 25% slower than the baseline
+  pub change: f64,
+  pub regressed: bool,
+}
+
+impl History {
+  /// Loads the history at `path`, starting a new one if it does not exist yet
+  pub fn load(path: &Path) -> Result<History, Box<dyn Error>> {
+    match path.exists() {
+      true => Ok(serde_json::from_str(&fs::read_to_string(path)?)?),
+      false => Ok(History::default()),
+    }
+  }
+
+  pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+    write_atomic(path, &serde_json::to_string_pretty(self)?)
+  }
+
+  /// The latest entry for this phase of the day that didn't regress, or the
+  /// latest one from `commit` when given
+  pub fn baseline(&self, day: AocDay, phase: &str, commit: Option<&str>) -> Option<&Entry> {
+    let AocDay::D(year, day) = day;
+    self.entries.iter().rev().find(|e| {
+      e.year == year
+        && e.day == day
+        && e.phase == phase
+        && match commit {
+          Some(c) => same_commit(&e.commit, c),
+          None => !e.regressed,
+        }
+    })
+  }
+
+  /// Compares each phase of `res` against its baseline. Phases slower by more
+  /// than `threshold` (eg `0.1` for 10%) are marked as regressed.
+  pub fn compare(
+    &self,
+    day: AocDay,
+    res: &BenchResult,
+    commit: Option<&str>,
+    threshold: f64,
+  ) -> Vec<Comparison> {
+    phases(res)
+      .into_iter()
+      .filter_map(|(phase, stats)| {
+        let baseline = self.baseline(day, &phase, commit)?.clone();
+        let change = stats.median.as_secs_f64() / baseline.median().as_secs_f64().max(1e-9) - 1.0;
+        Some(Comparison {
+          phase,
+          median: stats.median,
+          regressed: change > threshold,
+          change,
+          baseline,
+        })
+      })
+      .collect()
+  }
+
+  /// Appends each phase of `res`, marking those `cmp` found regressed so a
+  /// slowdown keeps being flagged until it's fixed
+  pub fn record(&mut self, day: AocDay, res: &BenchResult, commit: &str, cmp: &[Comparison]) {
+    let AocDay::D(year, day) = day;
+    let recorded = SystemTime::now()
+      .duration_since(UNIX_EPOCH)
+      .map_or(0, |d| d.as_secs());
+    for (phase, stats) in phases(res) {
+      self.entries.push(Entry {
+        year,
+        day,
+        commit: commit.to_string(),
+        recorded,
+        runs: res.runs,
+        min_ns: stats.min.as_nanos() as u64,
+        median_ns: stats.median.as_nanos() as u64,
+        mean_ns: stats.mean.as_nanos() as u64,
+        std_dev_ns: stats.std_dev.as_nanos() as u64,
+        regressed: cmp.iter().any(|c| c.phase == phase && c.regressed),
+        phase,
+      });
+    }
+  }
+}
+
+fn phases(res: &BenchResult) -> Vec<(String, Stats)> {
+  std::iter::once(("parse".to_string(), res.parse))
+    .chain(res.parts.iter().map(|(part, stats)| {
+      let phase = match part {
+        Part::One => "1",
+        Part::Two => "2",
+      };
+      (phase.to_string(), *stats)
+    }))
+    .collect()
+}
+
+/// Whether an entry recorded at `recorded` (as given by `current_commit`)
+/// belongs to `wanted`, which may be a full hash or any prefix of one
+fn same_commit(recorded: &str, wanted: &str) -> bool {
+  let recorded = recorded.strip_suffix("-dirty").unwrap_or(recorded);
+  // `git describe` puts the hash after a `-g` once there's a tag to describe from
+  let hash = recorded.rsplit_once("-g").map_or(recorded, |(_, h)| h);
+  recorded == wanted
+    || (!wanted.is_empty() && (hash.starts_with(wanted) || wanted.starts_with(hash)))
+}
+
+/// The full hash of `rev`, eg a branch, tag or abbreviated hash, or `rev`
+/// itself when git can't resolve it
+pub fn resolve_commit(rev: &str) -> String {
+  Command::new("git")
+    .args(["rev-parse", "--verify", "--quiet"])
+    .arg(format!("{}^{{commit}}", rev))
+    .output()
+    .ok()
+    .filter(|out| out.status.success())
+    .and_then(|out| String::from_utf8(out.stdout).ok())
+    .map(|s| s.trim().to_string())
+    .unwrap_or_else(|| rev.to_string())
+}
+
+/// The checked out commit, marked `-dirty` when there are local changes
+pub fn current_commit() -> String {
+  Command::new("git")
+    .args(["describe", "--always", "--dirty"])
+    .output()
+    .ok()
+    .filter(|out| out.status.success())
+    .and_then(|out| String::from_utf8(out.stdout).ok())
+    .map(|s| s.trim().to_string())
+    .unwrap_or_else(|| "unknown".to_string())
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn result(parse: u64, p1: u64) -> BenchResult {
+    let stats = |ms: u64| Stats::from_samples(&[Duration::from_millis(ms)]).unwrap();
+    BenchResult {
+      runs: 1,
+      parse: stats(parse),
+      parts: vec![(Part::One, stats(p1))],
+      answers: vec![],
+      unsolved: vec![],
+    }
+  }
+
+  #[test]
+  fn compare_against_baseline() {
+    let day = AocDay::D(2024, 16);
+    let mut history = History::default();
+    assert!(history.compare(day, &result(1, 10), None, 0.1).is_empty());
+
+    history.record(day, &result(1, 10), "aaa1234", &[]);
+    history.record(day, &result(1, 20), "bbb5678", &[]);
+    history.record(AocDay::D(2024, 17), &result(5, 50), "bbb5678", &[]);
+
+    let cmp = history.compare(day, &result(1, 23), None, 0.1);
+    assert_eq!(cmp.len(), 2);
+    assert_eq!(cmp[0].phase, "parse");
+    assert!(!cmp[0].regressed);
+    assert_eq!(cmp[1].baseline.commit, "bbb5678");
+    assert!(cmp[1].regressed);
+
+    let cmp = history.compare(day, &result(1, 23), Some("bbb5678"), 0.2);
+    assert!(!cmp[1].regressed);
+    let cmp = history.compare(day, &result(1, 23), Some("aaa1234"), 0.2);
+    assert!(cmp[1].regressed);
+    let cmp = history.compare(day, &result(1, 23), Some("aaa12349f0e"), 0.2);
+    assert_eq!(cmp[1].baseline.commit, "aaa1234");
+    assert!(history
+      .compare(day, &result(1, 23), Some("ccc9999"), 0.2)
+      .is_empty());
+  }
+
+  #[test]
+  fn regressed_runs_are_not_baselines() {
+    let day = AocDay::D(2024, 16);
+    let mut history = History::default();
+    history.record(day, &result(1, 10), "aaa1234", &[]);
+
+    let cmp = history.compare(day, &result(1, 20), None, 0.1);
+    assert!(cmp[1].regressed);
+    history.record(day, &result(1, 20), "bbb5678", &cmp);
+    assert!(history.entries[3].regressed);
+    assert!(!history.entries[2].regressed);
+
+    // Still slow on the next run, so still flagged against the fast one
+    let cmp = history.compare(day, &result(1, 20), None, 0.1);
+    assert_eq!(cmp[1].baseline.commit, "aaa1234");
+    assert!(cmp[1].regressed);
+    assert_eq!(
+      history.baseline(day, "parse", None).unwrap().commit,
+      "bbb5678"
+    );
+    assert_eq!(
+      history.baseline(day, "1", Some("bbb")).unwrap().median(),
+      Duration::from_millis(20)
+    );
+  }
+
+  #[test]
+  fn commits() {
+    assert!(same_commit("abc1234", "abc1234"));
+    assert!(same_commit("abc1234-dirty", "abc1234"));
+    assert!(same_commit("abc1234", "abc1234def5678"));
+    assert!(same_commit("abc1234", "abc"));
+    assert!(same_commit("v1.0-3-gabc1234", "abc1234def"));
+    assert!(!same_commit("abc1234", "abd"));
+    assert!(!same_commit("abc1234", ""));
+  }
+
+  #[test]
+  fn save_load() {
+    let path = std::env::temp_dir().join(format!("aoc-history-{}.json", std::process::id()));
+    let mut history = History::load(&path).unwrap();
+    assert!(history.entries.is_empty());
+    history.record(AocDay::D(2023, 1), &result(1, 2), "abc", &[]);
+    history.save(&path).unwrap();
+    let loaded = History::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.entries, history.entries);
+  }
+}
@@ -1,13 +1,32 @@
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod error;
+pub mod examples;
 pub mod grid;
+pub mod history;
+pub mod parse;
+pub mod point;
 pub mod search;
+pub mod sparse;
+#[cfg(test)]
+mod stand_in;
+pub mod submit;
 
-use std::error::Error;
+use std::error::Error as StdError;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
 use std::{env, fmt::Display};
 
-use reqwest::{blocking::Client, header};
+pub use answers::Answer;
+use client::AocClient;
+pub use error::Error;
+pub use examples::read_example;
+pub use point::Point;
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum AocDay {
   D(usize, usize),
 }
@@ -20,57 +39,536 @@ impl Display for AocDay {
   }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+  One,
+  Two,
+}
+
+impl Part {
+  pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    match self {
+      Part::One => write!(f, "Part 1"),
+      Part::Two => write!(f, "Part 2"),
+    }
+  }
+}
+
+impl FromStr for Part {
+  type Err = Box<dyn StdError>;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "1" => Ok(Part::One),
+      "2" => Ok(Part::Two),
+      _ => Err(format!("Unknown part: {}", s).into()),
+    }
+  }
+}
+
 pub trait Day: TryFrom<String, Error = Box<dyn std::error::Error>> {
-  fn new(d: AocDay) -> Result<Self, Box<dyn Error>> {
+  fn new(d: AocDay) -> Result<Self, Box<dyn StdError>> {
     Self::try_from(read_input(d)?)
   }
 
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>>;
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>>;
+  /// Loads the day's input then parses and runs it, see `run_input`
+  fn run_day(d: AocDay, parts: &[Part]) -> Result<DayResult, Error> {
+    Self::run_input(read_input(d)?, parts)
+  }
 
-  fn run(&self) -> Result<String, Box<dyn Error>> {
-    let mut ans = match self.p1() {
-      Ok(v) => format!("Part 1: {}", v),
-      Err(e) => format!("Part 1: {:?}", e),
-    };
-    ans = match self.p2() {
-      Ok(v) => format!("{}\nPart 2: {}", ans, v),
-      Err(e) => format!("{}\nPart 2: {:?}", ans, e),
-    };
-    Ok(ans)
+  /// Parses `input` and runs the requested parts, timing each phase separately
+  fn run_input(input: String, parts: &[Part]) -> Result<DayResult, Error> {
+    let now = Instant::now();
+    let solve = Self::try_from(input).map_err(Error::parse)?;
+    let parse = now.elapsed();
+    Ok(DayResult {
+      parse,
+      ..solve.run(parts)
+    })
+  }
+
+  fn p1(&self) -> Result<Answer, Box<dyn StdError>>;
+  fn p2(&self) -> Result<Answer, Box<dyn StdError>>;
+
+  fn solve(&self, part: Part) -> Result<Answer, Box<dyn StdError>> {
+    match part {
+      Part::One => self.p1(),
+      Part::Two => self.p2(),
+    }
+  }
+
+  /// Runs only the requested parts, in the order given. Parsing has already
+  /// happened by this point so `parse` is left at zero.
+  fn run(&self, parts: &[Part]) -> DayResult {
+    run_parts(parts, |part| self.solve(part))
   }
 }
 
-fn read_input(day: AocDay) -> Result<String, Box<dyn Error>> {
-  let path = match day {
-    AocDay::D(_, v @ 1..=25) => PathBuf::from(format!("./input/d{}", v)),
-    AocDay::D(_, _) => return Err("Unknown Day Provided".into()),
+/// A day whose parsed form borrows from its input, for days that would
+/// otherwise copy every line into its own `String`. The input outlives
+/// `Parsed`, so it can hold `&str` slices or work on `input.as_bytes()`.
+pub trait BorrowedDay {
+  type Parsed<'a>;
+
+  fn parse(input: &str) -> Result<Self::Parsed<'_>, Box<dyn StdError>>;
+
+  fn p1(parsed: &Self::Parsed<'_>) -> Result<Answer, Box<dyn StdError>>;
+  fn p2(parsed: &Self::Parsed<'_>) -> Result<Answer, Box<dyn StdError>>;
+
+  fn solve(parsed: &Self::Parsed<'_>, part: Part) -> Result<Answer, Box<dyn StdError>> {
+    match part {
+      Part::One => Self::p1(parsed),
+      Part::Two => Self::p2(parsed),
+    }
+  }
+
+  /// Loads the day's input then parses and runs it, see `run_input`
+  fn run_day(d: AocDay, parts: &[Part]) -> Result<DayResult, Error> {
+    Self::run_input(read_input(d)?, parts)
+  }
+
+  /// Parses `input` and runs the requested parts, timing each phase separately
+  fn run_input(input: String, parts: &[Part]) -> Result<DayResult, Error> {
+    let now = Instant::now();
+    let parsed = Self::parse(&input).map_err(Error::parse)?;
+    let parse = now.elapsed();
+    Ok(DayResult {
+      parse,
+      ..run_parts(parts, |part| Self::solve(&parsed, part))
+    })
+  }
+}
+
+/// Times each of `parts` in turn, leaving `parse` at zero
+fn run_parts(
+  parts: &[Part],
+  solve: impl Fn(Part) -> Result<Answer, Box<dyn StdError>>,
+) -> DayResult {
+  DayResult {
+    parse: Duration::ZERO,
+    parts: parts
+      .iter()
+      .map(|part| {
+        let now = Instant::now();
+        let answer = solve(*part);
+        PartResult {
+          part: *part,
+          answer,
+          elapsed: now.elapsed(),
+        }
+      })
+      .collect(),
+  }
+}
+
+/// Parses an input and runs the given parts, ie `Day::run_input` for one day
+pub type Solver = fn(String, &[Part]) -> Result<DayResult, Error>;
+
+/// A day's entry in its year crate's `DAYS`, as generated by
+/// `import_aoc_solutions!` from the day's module and `#[aoc(...)]` attribute
+#[derive(Clone, Copy, Debug)]
+pub struct DayInfo {
+  pub day: usize,
+  /// The module holding the solution, eg `day14`
+  pub module: &'static str,
+  /// The solution's source, relative to the year crate
+  pub path: &'static str,
+  pub title: Option<&'static str>,
+  /// Takes long enough that it only runs when asked for
+  pub slow: bool,
+  pub solver: Solver,
+  /// Other approaches to the same day, from `dayN_<variant>.rs`
+  pub variants: &'static [Variant],
+}
+
+impl DayInfo {
+  /// The solver for the named variant, or the day's own when no name is given
+  pub fn solver(&self, variant: Option<&str>) -> Option<Solver> {
+    match variant {
+      None => Some(self.solver),
+      Some(name) => self
+        .variants
+        .iter()
+        .find(|v| v.name == name)
+        .map(|v| v.solver),
+    }
+  }
+}
+
+/// An alternative solution to a day, kept in `dayN_<name>.rs` next to it
+#[derive(Clone, Copy, Debug)]
+pub struct Variant {
+  pub name: &'static str,
+  pub module: &'static str,
+  pub path: &'static str,
+  pub solver: Solver,
+}
+
+/// How many puzzles the year has, as there are only 12 from 2025 on
+pub fn days_in(year: usize) -> usize {
+  match year {
+    ..=2024 => 25,
+    _ => 12,
+  }
+}
+
+/// The answer (or error) a single part produced
+pub struct PartResult {
+  pub part: Part,
+  pub answer: Result<Answer, Box<dyn StdError>>,
+  pub elapsed: Duration,
+}
+
+impl PartResult {
+  /// The part returned `Error::Unsolved` rather than an answer
+  pub fn is_unsolved(&self) -> bool {
+    self
+      .answer
+      .as_ref()
+      .is_err_and(|e| matches!(e.downcast_ref::<Error>(), Some(Error::Unsolved)))
+  }
+
+  /// Returned an error other than `Error::Unsolved`
+  pub fn is_failed(&self) -> bool {
+    self.answer.is_err() && !self.is_unsolved()
+  }
+}
+
+/// The outcome of every part run for a day
+pub struct DayResult {
+  /// Time spent in `TryFrom<String>`, excluding reading the input
+  pub parse: Duration,
+  pub parts: Vec<PartResult>,
+}
+
+impl DayResult {
+  /// No part failed, though some may not be solved yet
+  pub fn is_ok(&self) -> bool {
+    !self.parts.iter().any(|p| p.is_failed())
+  }
+
+  pub fn get(&self, part: Part) -> Option<&PartResult> {
+    self.parts.iter().find(|p| p.part == part)
+  }
+
+  /// Parse time plus the time of every part
+  pub fn elapsed(&self) -> Duration {
+    self.parse + self.parts.iter().map(|p| p.elapsed).sum::<Duration>()
+  }
+
+  /// The first part that failed as an error, or the result if none did.
+  /// Unsolved parts are left in the result.
+  pub fn into_result(mut self) -> Result<Self, Error> {
+    match self.parts.iter().position(|p| p.is_failed()) {
+      Some(idx) => {
+        let failed = self.parts.swap_remove(idx);
+        Err(Error::SolverFailed {
+          part: failed.part,
+          source: failed.answer.err().unwrap(),
+        })
+      }
+      None => Ok(self),
+    }
+  }
+}
+
+impl Display for DayResult {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    for (idx, p) in self.parts.iter().enumerate() {
+      if idx > 0 {
+        writeln!(f)?;
+      }
+      match &p.answer {
+        Ok(v) => write!(f, "{}: {}", p.part, v)?,
+        Err(e) if p.is_unsolved() => write!(f, "{}: {}", p.part, e)?,
+        Err(e) => write!(f, "{}: {:?}", p.part, e)?,
+      }
+    }
+    Ok(())
+  }
+}
+
+/// The root all inputs are cached under, `AOC_INPUT_DIR` or else `input/` at the repo root.
+/// Inputs used to live in `y{year}/input/d{day}`, move them over with
+/// `for y in y20*; do [ -d $y/input ] && mkdir -p input/${y#y} && mv $y/input/* input/${y#y}/; done`
+pub fn input_dir() -> PathBuf {
+  env::var_os("AOC_INPUT_DIR")
+    .map(PathBuf::from)
+    .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("../input"))
+}
+
+/// Where the day's input is cached, ie `{input_dir}/{year}/d{day}`
+pub fn input_path(day: AocDay) -> Result<PathBuf, Error> {
+  match day {
+    AocDay::D(year, v @ 1..=25) => Ok(input_dir().join(year.to_string()).join(format!("d{}", v))),
+    AocDay::D(_, _) => Err(Error::UnknownDay(day)),
+  }
+}
+
+pub fn read_input(day: AocDay) -> Result<String, Error> {
+  let path = input_path(day)?;
+  match read_cached(&path)? {
+    Cached::Valid(input) => Ok(input),
+    Cached::Missing => download_input(day, &path),
+    Cached::Invalid(reason) => download_input(day, &path).map_err(|e| match e {
+      Error::InputMissing(path) => Error::InputInvalid { path, reason },
+      e => e,
+    }),
+  }
+}
+
+/// What the input cache holds for a day
+#[derive(Debug, PartialEq)]
+enum Cached {
+  Valid(String),
+  Missing,
+  /// There, but failing `check_input` for the given reason
+  Invalid(String),
+}
+
+fn read_cached(path: &Path) -> Result<Cached, Error> {
+  let input = match fs::read_to_string(path) {
+    Ok(input) => input,
+    Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+      println!("Attempting input download...");
+      return Ok(Cached::Missing);
+    }
+    Err(source) => {
+      return Err(Error::InputUnreadable {
+        path: path.to_path_buf(),
+        source,
+      })
+    }
   };
+  match check_input(&input) {
+    Ok(()) => Ok(Cached::Valid(input)),
+    Err(e) => {
+      println!(
+        "Cached input {} is invalid ({}), re-downloading...",
+        path.display(),
+        e
+      );
+      Ok(Cached::Invalid(e))
+    }
+  }
+}
 
-  if !path.exists() {
-    println!("Attempting input download...");
-    download_input(day, &path)?;
+/// Sanity checks an input, since a bad one is otherwise used forever
+pub fn check_input(input: &str) -> Result<(), String> {
+  if input.trim().is_empty() {
+    return Err("it is empty".into());
+  }
+  if client::looks_like_html(input) {
+    return Err("it is an HTML page".into());
   }
+  Ok(())
+}
 
-  match fs::read_to_string(path) {
-    Err(err) => Err(err.into()),
-    Ok(s) => Ok(s),
+fn download_input(day: AocDay, out: &Path) -> Result<String, Error> {
+  if env::var_os("AOC_SESSION").is_none() {
+    return Err(Error::InputMissing(out.to_path_buf()));
   }
+  let failed = |source| Error::DownloadFailed { day, source };
+  let client = AocClient::from_env().map_err(failed)?;
+  let input = client.input(day).map_err(failed)?;
+  check_input(&input).map_err(|e| failed(format!("the input is invalid, {}", e).into()))?;
+  write_atomic(out, &input).map_err(failed)?;
+  Ok(input)
 }
 
-fn download_input(day: AocDay, out: &PathBuf) -> Result<(), Box<dyn Error>> {
-  let session = env::var("AOC_SESSION")?;
-  let dl_url = format!("https://adventofcode.com/{}/input", day);
-  let client = Client::builder().gzip(true).build()?;
-  let mut response = client
-    .get(&dl_url)
-    .header(header::COOKIE, format!("session={}", session))
-    .send()?
-    .error_for_status()?;
-  if let Some(parent) = out.parent() {
-    fs::create_dir_all(parent)?;
+/// Writes to a temp file alongside `path` and renames it into place, so an
+/// interrupted write never leaves a truncated file behind
+pub fn write_atomic(path: &Path, contents: &str) -> Result<(), Box<dyn StdError>> {
+  let parent = path.parent().ok_or("Path has no parent directory")?;
+  fs::create_dir_all(parent)?;
+  let name = path
+    .file_name()
+    .ok_or("Path has no file name")?
+    .to_string_lossy();
+  let tmp = parent.join(format!(".{}.{}.tmp", name, std::process::id()));
+  fs::write(&tmp, contents)?;
+  if let Err(e) = fs::rename(&tmp, path) {
+    let _ = fs::remove_file(&tmp);
+    return Err(e.into());
   }
-  let mut file = std::fs::File::create(out)?;
-  response.copy_to(&mut file)?;
   Ok(())
 }
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  struct Solve(i64);
+  impl TryFrom<String> for Solve {
+    type Error = Box<dyn StdError>;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+      Ok(Solve(value.trim().parse()?))
+    }
+  }
+  impl Day for Solve {
+    fn p1(&self) -> Result<Answer, Box<dyn StdError>> {
+      Ok(Answer::from(self.0 * 2))
+    }
+
+    fn p2(&self) -> Result<Answer, Box<dyn StdError>> {
+      Err("Not solved".into())
+    }
+  }
+
+  #[test]
+  fn run_results() {
+    let solve = Solve::try_from("21".to_string()).unwrap();
+    let res = solve.run(&Part::ALL);
+    assert!(!res.is_ok());
+    assert_eq!(*res.get(Part::One).unwrap().answer.as_ref().unwrap(), 42);
+    assert!(res.get(Part::Two).unwrap().answer.is_err());
+    assert_eq!(res.to_string(), "Part 1: 42\nPart 2: \"Not solved\"");
+
+    let err = res.into_result().err().unwrap();
+    assert!(matches!(
+      err,
+      Error::SolverFailed {
+        part: Part::Two,
+        ..
+      }
+    ));
+    assert_eq!(err.to_string(), "Part 2 failed: Not solved");
+
+    let res = solve.run(&[Part::One]);
+    assert!(res.is_ok());
+    assert!(res.get(Part::Two).is_none());
+    assert_eq!(res.parse, Duration::ZERO);
+  }
+
+  struct Half(i64);
+  impl TryFrom<String> for Half {
+    type Error = Box<dyn StdError>;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+      Ok(Half(value.trim().parse()?))
+    }
+  }
+  impl Day for Half {
+    fn p1(&self) -> Result<Answer, Box<dyn StdError>> {
+      Ok(Answer::from(self.0))
+    }
+
+    fn p2(&self) -> Result<Answer, Box<dyn StdError>> {
+      Err(Error::Unsolved.into())
+    }
+  }
+
+  #[test]
+  fn unsolved_parts() {
+    let res = Half(3).run(&Part::ALL);
+    assert!(res.is_ok());
+    assert!(!res.get(Part::One).unwrap().is_unsolved());
+    assert!(res.get(Part::Two).unwrap().is_unsolved());
+    assert!(!res.get(Part::Two).unwrap().is_failed());
+    assert_eq!(res.to_string(), "Part 1: 3\nPart 2: Not solved");
+    let res = res.into_result().unwrap();
+    assert_eq!(res.parts.len(), 2);
+
+    let res = Solve(3).run(&Part::ALL);
+    assert!(!res.get(Part::Two).unwrap().is_unsolved());
+    assert!(res.get(Part::Two).unwrap().is_failed());
+  }
+
+  struct Words;
+  impl BorrowedDay for Words {
+    type Parsed<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Vec<&str>, Box<dyn StdError>> {
+      Ok(input.split_whitespace().collect())
+    }
+
+    fn p1(parsed: &Vec<&str>) -> Result<Answer, Box<dyn StdError>> {
+      Ok(Answer::from(parsed.len()))
+    }
+
+    fn p2(parsed: &Vec<&str>) -> Result<Answer, Box<dyn StdError>> {
+      parsed
+        .iter()
+        .max_by_key(|w| w.len())
+        .map(|w| Answer::from(*w))
+        .ok_or_else(|| "No words".into())
+    }
+  }
+
+  #[test]
+  fn borrowed_run() {
+    let res = Words::run_input("a bb ccc dd".to_string(), &Part::ALL).unwrap();
+    assert!(res.is_ok());
+    assert_eq!(*res.get(Part::One).unwrap().answer.as_ref().unwrap(), 4);
+    assert_eq!(*res.get(Part::Two).unwrap().answer.as_ref().unwrap(), "ccc");
+
+    let res = Words::run_input(String::new(), &[Part::Two]).unwrap();
+    assert!(!res.is_ok());
+  }
+
+  #[test]
+  fn run_input_phases() {
+    let res = Solve::run_input("4".to_string(), &[Part::Two, Part::One]).unwrap();
+    let order: Vec<Part> = res.parts.iter().map(|p| p.part).collect();
+    assert_eq!(order, vec![Part::Two, Part::One]);
+    assert!(res.elapsed() >= res.parse);
+    assert!(matches!(
+      Solve::run_input("four".to_string(), &Part::ALL),
+      Err(Error::ParseFailed { line: None, .. })
+    ));
+  }
+
+  #[test]
+  fn input_checks() {
+    assert!(check_input("1 2\n3 4\n").is_ok());
+    assert!(check_input("\n  \n").is_err());
+    assert!(check_input("<!DOCTYPE html>\n<html></html>").is_err());
+
+    let dir = env::temp_dir().join(format!("aoc-atomic-{}", std::process::id()));
+    let path = dir.join("2024/d1");
+    write_atomic(&path, "first").unwrap();
+    write_atomic(&path, "second").unwrap();
+    let contents = fs::read_to_string(&path).unwrap();
+    let files = fs::read_dir(path.parent().unwrap()).unwrap().count();
+    fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(contents, "second");
+    assert_eq!(files, 1);
+  }
+
+  #[test]
+  fn cached_inputs() {
+    let dir = env::temp_dir().join(format!("aoc-cached-{}", std::process::id()));
+    fs::create_dir_all(dir.join("dir")).unwrap();
+    fs::write(dir.join("ok"), "1 2\n").unwrap();
+    fs::write(dir.join("html"), "<html></html>").unwrap();
+    fs::write(dir.join("binary"), [0xff, 0xfe, 0x00]).unwrap();
+
+    let ok = read_cached(&dir.join("ok"));
+    let html = read_cached(&dir.join("html"));
+    let missing = read_cached(&dir.join("missing"));
+    let binary = read_cached(&dir.join("binary"));
+    let unreadable = read_cached(&dir.join("dir"));
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(ok.unwrap(), Cached::Valid("1 2\n".into()));
+    assert_eq!(html.unwrap(), Cached::Invalid("it is an HTML page".into()));
+    assert_eq!(missing.unwrap(), Cached::Missing);
+    assert!(matches!(binary, Err(Error::InputUnreadable { .. })));
+    assert!(matches!(unreadable, Err(Error::InputUnreadable { .. })));
+  }
+
+  #[test]
+  fn input_layout() {
+    let path = input_path(AocDay::D(2023, 7)).unwrap();
+    assert!(path.ends_with("2023/d7"));
+    assert!(matches!(
+      input_path(AocDay::D(2023, 26)),
+      Err(Error::UnknownDay(AocDay::D(2023, 26)))
+    ));
+  }
+}
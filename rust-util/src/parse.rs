@@ -0,0 +1,145 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::Error;
+
+/// Whether a line that fails to parse is an error or is skipped
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+  /// Fail on the first bad line, reporting where it is
+  Strict,
+  /// Skip bad lines, as a `filter_map` over the lines would
+  Lenient,
+}
+
+/// One line of an input, with helpers that report failures against its
+/// 1-based line number, column and text
+#[derive(Clone, Copy, Debug)]
+pub struct Line<'a> {
+  pub number: usize,
+  pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+  /// An error for the whole line
+  pub fn error(&self, reason: impl Display) -> Error {
+    self.error_in(None, reason)
+  }
+
+  fn error_in(&self, column: Option<usize>, reason: impl Display) -> Error {
+    Error::ParseFailed {
+      line: Some(self.number),
+      column,
+      text: Some(self.text.to_string()),
+      reason: reason.to_string(),
+    }
+  }
+
+  /// An error pointing at `part`, which should be a slice of this line
+  pub fn error_at(&self, part: &str, reason: impl Display) -> Error {
+    let offset = (part.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+    let column = self
+      .text
+      .get(..offset)
+      .map(|before| before.chars().count() + 1);
+    self.error_in(column, reason)
+  }
+
+  /// Parses `part` of this line
+  pub fn parse<T>(&self, part: &'a str) -> Result<T, Error>
+  where
+    T: FromStr,
+    T::Err: Display,
+  {
+    part
+      .parse()
+      .map_err(|e| self.error_at(part, format!("`{}` {}", part, e)))
+  }
+
+  pub fn split_once(&self, part: &'a str, delim: &str) -> Result<(&'a str, &'a str), Error> {
+    part
+      .split_once(delim)
+      .ok_or_else(|| self.error_at(part, format!("expected `{}`", delim)))
+  }
+
+  pub fn strip_prefix(&self, part: &'a str, prefix: &str) -> Result<&'a str, Error> {
+    part
+      .strip_prefix(prefix)
+      .ok_or_else(|| self.error_at(part, format!("expected `{}`", prefix)))
+  }
+}
+
+/// The input's lines, numbered from 1
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+  input.lines().enumerate().map(|(idx, text)| Line {
+    number: idx + 1,
+    text,
+  })
+}
+
+/// Parses each line of `input` with `f`, failing or skipping bad lines as
+/// `mode` says
+pub fn parse_lines<'a, T>(
+  input: &'a str,
+  mode: Mode,
+  mut f: impl FnMut(&Line<'a>) -> Result<T, Error>,
+) -> Result<Vec<T>, Error> {
+  let mut parsed = Vec::new();
+  for line in lines(input) {
+    match (f(&line), mode) {
+      (Ok(v), _) => parsed.push(v),
+      (Err(e), Mode::Strict) => return Err(e),
+      (Err(_), Mode::Lenient) => (),
+    }
+  }
+  Ok(parsed)
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn pair(line: &Line) -> Result<(i64, i64), Error> {
+    let (a, b) = line.split_once(line.strip_prefix(line.text, "p=")?, ",")?;
+    Ok((line.parse(a)?, line.parse(b)?))
+  }
+
+  #[test]
+  fn strict_and_lenient() {
+    let input = "p=1,2\np=3,x4\np=5,6\n";
+    assert_eq!(
+      parse_lines(input, Mode::Lenient, pair).unwrap(),
+      vec![(1, 2), (5, 6)]
+    );
+
+    let err = parse_lines(input, Mode::Strict, pair).unwrap_err();
+    let Error::ParseFailed {
+      line, column, text, ..
+    } = &err
+    else {
+      panic!("Expected a parse error, got {}", err)
+    };
+    assert_eq!((*line, *column), (Some(2), Some(5)));
+    assert_eq!(text.as_deref(), Some("p=3,x4"));
+    assert_eq!(
+      err.to_string(),
+      "Parse failed at line 2, column 5 (`p=3,x4`): `x4` invalid digit found in string"
+    );
+  }
+
+  #[test]
+  fn columns() {
+    let line = lines("skip\nαβ=7;").nth(1).unwrap();
+    assert_eq!(line.number, 2);
+    let rest = line.strip_prefix(&line.text[4..], "=").unwrap();
+    assert_eq!(rest, "7;");
+    let Error::ParseFailed { column, .. } = line.split_once(&line.text[5..], ",").unwrap_err()
+    else {
+      unreachable!()
+    };
+    assert_eq!(column, Some(4));
+    let Error::ParseFailed { column, .. } = line.error_at("elsewhere", "nope") else {
+      unreachable!()
+    };
+    assert_eq!(column, None);
+  }
+}
@@ -0,0 +1,168 @@
+use std::{
+  fmt::Display,
+  ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use crate::grid::Dir;
+
+/// A signed `x`/`y` coordinate, with `y` growing downwards (south) like a
+/// `Grid`'s rows. Build one with `Point::new(x, y)` or, from a grid location,
+/// `Point::from_yx(y, x)`, so the axis order is always spelled out.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+  pub x: i64,
+  pub y: i64,
+}
+
+impl Point {
+  pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+  pub const fn new(x: i64, y: i64) -> Self {
+    Point { x, y }
+  }
+
+  /// The point at a grid's `(y, x)`
+  pub fn from_yx(y: usize, x: usize) -> Self {
+    Point::new(x as i64, y as i64)
+  }
+
+  /// This point as a grid's `(y, x)`, if neither coordinate is negative
+  pub fn yx(&self) -> Option<(usize, usize)> {
+    Some((usize::try_from(self.y).ok()?, usize::try_from(self.x).ok()?))
+  }
+
+  /// The point `step` tiles away in `dir`
+  pub fn step(&self, dir: &Dir, step: i64) -> Point {
+    *self + dir.offset() * step
+  }
+
+  /// Reduces each coordinate into `0..size`, as on a board of `size` that
+  /// wraps around at its edges
+  pub fn rem_euclid(&self, size: Point) -> Point {
+    Point::new(self.x.rem_euclid(size.x), self.y.rem_euclid(size.y))
+  }
+
+  /// How many whole `size`s this point is from the origin along each axis,
+  /// rounding towards negative infinity
+  pub fn div_euclid(&self, size: Point) -> Point {
+    Point::new(self.x.div_euclid(size.x), self.y.div_euclid(size.y))
+  }
+
+  pub fn manhattan(&self, other: &Point) -> i64 {
+    (self.x - other.x).abs() + (self.y - other.y).abs()
+  }
+
+  /// Distance when diagonal moves cost the same as straight ones
+  pub fn chebyshev(&self, other: &Point) -> i64 {
+    (self.x - other.x).abs().max((self.y - other.y).abs())
+  }
+}
+
+impl Dir {
+  /// The change in position from one step in this direction
+  pub fn offset(&self) -> Point {
+    let (x, y) = match self {
+      Dir::N => (0, -1),
+      Dir::E => (1, 0),
+      Dir::S => (0, 1),
+      Dir::W => (-1, 0),
+      Dir::Idle => (0, 0),
+      Dir::NE => (1, -1),
+      Dir::NW => (-1, -1),
+      Dir::SE => (1, 1),
+      Dir::SW => (-1, 1),
+    };
+    Point::new(x, y)
+  }
+}
+
+impl Display for Point {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "({}, {})", self.x, self.y)
+  }
+}
+
+impl Add for Point {
+  type Output = Point;
+
+  fn add(self, rhs: Point) -> Point {
+    Point::new(self.x + rhs.x, self.y + rhs.y)
+  }
+}
+
+impl Sub for Point {
+  type Output = Point;
+
+  fn sub(self, rhs: Point) -> Point {
+    Point::new(self.x - rhs.x, self.y - rhs.y)
+  }
+}
+
+impl Mul<i64> for Point {
+  type Output = Point;
+
+  fn mul(self, rhs: i64) -> Point {
+    Point::new(self.x * rhs, self.y * rhs)
+  }
+}
+
+impl Neg for Point {
+  type Output = Point;
+
+  fn neg(self) -> Point {
+    Point::new(-self.x, -self.y)
+  }
+}
+
+impl AddAssign for Point {
+  fn add_assign(&mut self, rhs: Point) {
+    *self = *self + rhs;
+  }
+}
+
+impl SubAssign for Point {
+  fn sub_assign(&mut self, rhs: Point) {
+    *self = *self - rhs;
+  }
+}
+
+impl Add<Dir> for Point {
+  type Output = Point;
+
+  fn add(self, rhs: Dir) -> Point {
+    self + rhs.offset()
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn arithmetic() {
+    let mut p = Point::new(2, 3);
+    assert_eq!(p + Point::new(1, -1), Point::new(3, 2));
+    assert_eq!(p - Point::new(1, -1), Point::new(1, 4));
+    assert_eq!(p * 3, Point::new(6, 9));
+    assert_eq!(-p, Point::new(-2, -3));
+    p += Dir::N.offset();
+    assert_eq!(p, Point::new(2, 2));
+    p -= Point::new(2, 2);
+    assert_eq!(p, Point::ORIGIN);
+  }
+
+  #[test]
+  fn dirs_and_distances() {
+    let p = Point::from_yx(1, 4);
+    assert_eq!(p, Point::new(4, 1));
+    assert_eq!(p + Dir::S, Point::new(4, 2));
+    assert_eq!(p.step(&Dir::NW, 2), Point::new(2, -1));
+    assert_eq!(p.step(&Dir::NW, 2).yx(), None);
+    assert_eq!(p.yx(), Some((1, 4)));
+    assert_eq!(p.manhattan(&Point::new(1, 5)), 7);
+    assert_eq!(p.chebyshev(&Point::new(1, 5)), 4);
+    let size = Point::new(3, 2);
+    assert_eq!(Point::new(-4, 5).rem_euclid(size), Point::new(2, 1));
+    assert_eq!(Point::new(-4, 5).div_euclid(size), Point::new(-2, 2));
+  }
+}
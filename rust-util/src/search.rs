@@ -1,12 +1,15 @@
 use std::{
   cmp::Reverse,
   collections::{BinaryHeap, HashMap},
+  hash::Hash,
 };
 
 use crate::grid::{Dir, Grid};
 
 pub fn dijkstra(grid: Grid<usize>, minstep: usize, maxstep: usize) -> usize {
-  let mut dists = HashMap::new();
+  // Cheapest cost to each tile, by the direction it was entered in. Only the
+  // start is `Idle`, and nothing is cheaper than it.
+  let mut dists = Grid::filled(grid.height(), grid.width(), [usize::MAX; 4]);
   let mut q = BinaryHeap::from_iter([Reverse((0, (0, 0, Dir::Idle)))]);
 
   while let Some(Reverse((cost, (y, x, d)))) = q.pop() {
@@ -16,7 +19,7 @@ pub fn dijkstra(grid: Grid<usize>, minstep: usize, maxstep: usize) -> usize {
     }
 
     // Skip this path if we've found a shorter path to here
-    if dists.get(&(y, x, d.clone())).is_some_and(|&c| cost > c) {
+    if d.index().is_some_and(|i| cost > dists.at(y, x).unwrap()[i]) {
       continue;
     }
 
@@ -36,13 +39,64 @@ pub fn dijkstra(grid: Grid<usize>, minstep: usize, maxstep: usize) -> usize {
           continue;
         }
 
-        let key = (rr, cc, dir.clone());
-        if next_cost < *dists.get(&key).unwrap_or(&usize::MAX) {
-          dists.insert(key.clone(), next_cost);
-          q.push(Reverse((next_cost, key.clone())));
+        let best = &mut dists.at_mut(rr, cc).unwrap()[dir.index().unwrap()];
+        if next_cost < *best {
+          *best = next_cost;
+          q.push(Reverse((next_cost, (rr, cc, dir))));
         }
       }
     }
   }
   unreachable!("Did not find path to target")
 }
+
+/// Cheapest cost from `start` to any state satisfying `is_goal`, where `next`
+/// gives each state's neighbours along with the cost of moving to them
+pub fn shortest_path<S, I>(
+  start: S,
+  is_goal: impl Fn(&S) -> bool,
+  next: impl Fn(&S) -> I,
+) -> Option<usize>
+where
+  S: Clone + Eq + Hash + Ord,
+  I: IntoIterator<Item = (S, usize)>,
+{
+  let mut dists = HashMap::from([(start.clone(), 0)]);
+  let mut q = BinaryHeap::from([Reverse((0, start))]);
+
+  while let Some(Reverse((cost, state))) = q.pop() {
+    if is_goal(&state) {
+      return Some(cost);
+    }
+    // Skip this path if we've found a cheaper path to here
+    if dists.get(&state).is_some_and(|&c| cost > c) {
+      continue;
+    }
+    for (n, step) in next(&state) {
+      let next_cost = cost + step;
+      if next_cost < *dists.get(&n).unwrap_or(&usize::MAX) {
+        dists.insert(n.clone(), next_cost);
+        q.push(Reverse((next_cost, n)));
+      }
+    }
+  }
+  None
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn shortest_paths() {
+    // 0 -> 1 costs 4 directly but 3 through 2
+    let edges = |n: &usize| match n {
+      0 => vec![(1, 4), (2, 1)],
+      2 => vec![(1, 2)],
+      _ => vec![],
+    };
+    assert_eq!(shortest_path(0, |n| *n == 1, edges), Some(3));
+    assert_eq!(shortest_path(0, |n| *n == 0, edges), Some(0));
+    assert_eq!(shortest_path(1, |n| *n == 0, edges), None);
+  }
+}
@@ -0,0 +1,212 @@
+use std::{
+  collections::HashMap,
+  fmt::Display,
+  io::{Stdout, Write},
+};
+
+use crate::{
+  grid::{Dir, Grid},
+  point::Point,
+};
+
+/// An unbounded grid holding only the tiles that have been `put`, for boards
+/// that grow in any direction. Coordinates are signed `Point`s.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+  tiles: HashMap<Point, T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+  fn default() -> Self {
+    SparseGrid {
+      tiles: HashMap::new(),
+    }
+  }
+}
+
+impl<T: Display> SparseGrid<T> {
+  /// Prints the bounding box of the set tiles, with `.` for unset ones
+  pub fn print(&self) {
+    self.rows(|line| println!("{}", line));
+  }
+  pub fn write(&self, stdout: &mut Stdout) {
+    self.rows(|line| writeln!(stdout, "{}", line).unwrap());
+  }
+
+  fn rows(&self, mut out: impl FnMut(String)) {
+    let Some((min, max)) = self.bounds() else {
+      return;
+    };
+    for y in min.y..=max.y {
+      out(
+        (min.x..=max.x)
+          .map(|x| match self.at(Point::new(x, y)) {
+            Some(t) => t.to_string(),
+            None => ".".to_string(),
+          })
+          .collect(),
+      );
+    }
+  }
+}
+
+impl<T: Clone> SparseGrid<T> {
+  /// The tiles of `grid` that `keep` accepts, at the same `(y, x)`
+  pub fn from_grid(grid: &Grid<T>, keep: impl Fn(&T) -> bool) -> Self {
+    SparseGrid {
+      tiles: grid
+        .iter_points()
+        .filter(|(_, t)| keep(t))
+        .map(|(p, t)| (p, t.clone()))
+        .collect(),
+    }
+  }
+
+  /// A dense copy of the bounding box, with `empty` for unset tiles, along with
+  /// the `Point` that its `(0, 0)` came from
+  pub fn to_grid(&self, empty: T) -> (Grid<T>, Point) {
+    let Some((min, max)) = self.bounds() else {
+      return (Grid::filled(0, 0, empty), Point::ORIGIN);
+    };
+    let size = max - min;
+    let mut grid = Grid::filled(size.y as usize + 1, size.x as usize + 1, empty);
+    for (p, t) in self.tiles.iter() {
+      let (y, x) = (*p - min).yx().unwrap();
+      grid.put(y, x, t.clone());
+    }
+    (grid, min)
+  }
+}
+
+impl<T: Clone> From<&Grid<T>> for SparseGrid<T> {
+  fn from(grid: &Grid<T>) -> Self {
+    SparseGrid::from_grid(grid, |_| true)
+  }
+}
+
+impl<T> SparseGrid<T> {
+  pub fn new() -> Self {
+    SparseGrid::default()
+  }
+
+  /// How many tiles are set
+  pub fn len(&self) -> usize {
+    self.tiles.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.tiles.is_empty()
+  }
+
+  /// The top left and bottom right corners of the set tiles, if there are any
+  pub fn bounds(&self) -> Option<(Point, Point)> {
+    let mut points = self.tiles.keys();
+    let first = *points.next()?;
+    Some(points.fold((first, first), |(min, max), p| {
+      (
+        Point::new(min.x.min(p.x), min.y.min(p.y)),
+        Point::new(max.x.max(p.x), max.y.max(p.y)),
+      )
+    }))
+  }
+
+  pub fn at(&self, p: Point) -> Option<&T> {
+    self.tiles.get(&p)
+  }
+
+  pub fn at_mut(&mut self, p: Point) -> Option<&mut T> {
+    self.tiles.get_mut(&p)
+  }
+
+  /// Never out of bounds, any `Point` is on the grid
+  pub fn step(&self, p: Point, step: i64, dir: &Dir) -> Point {
+    p.step(dir, step)
+  }
+
+  pub fn at_step(&self, p: Point, step: i64, dir: &Dir) -> Option<(Point, &T)> {
+    let p = self.step(p, step, dir);
+    self.at(p).map(|t| (p, t))
+  }
+
+  pub fn put(&mut self, p: Point, val: T) {
+    self.tiles.insert(p, val);
+  }
+
+  pub fn remove(&mut self, p: Point) -> Option<T> {
+    self.tiles.remove(&p)
+  }
+
+  /// The set tiles next to `p` in each of `dirs`, as (Point, Dir, T)
+  pub fn neighbors_with(
+    &self,
+    p: Point,
+    dirs: impl IntoIterator<Item = Dir>,
+  ) -> impl Iterator<Item = (Point, Dir, &T)> {
+    dirs
+      .into_iter()
+      .filter_map(move |d| self.at_step(p, 1, &d).map(|(p, t)| (p, d, t)))
+  }
+
+  pub fn neighbors4(&self, p: Point) -> impl Iterator<Item = (Point, Dir, &T)> {
+    self.neighbors_with(p, Dir::CARDINAL)
+  }
+
+  pub fn neighbors8(&self, p: Point) -> impl Iterator<Item = (Point, Dir, &T)> {
+    self.neighbors_with(p, Dir::ALL)
+  }
+
+  /// Iterate through the set tiles in (Point, T) pairs, in no particular order
+  pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+    self.tiles.iter().map(|(p, t)| (*p, t))
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn unbounded() {
+    let mut g = SparseGrid::new();
+    assert_eq!(g.bounds(), None);
+    g.put(Point::new(-3, 2), 'a');
+    g.put(Point::new(4, -1), 'b');
+    g.put(Point::new(4, 0), 'c');
+    assert_eq!(g.len(), 3);
+    assert_eq!(g.bounds(), Some((Point::new(-3, -1), Point::new(4, 2))));
+    assert_eq!(
+      g.at_step(Point::new(4, -1), 1, &Dir::S),
+      Some((Point::new(4, 0), &'c'))
+    );
+    let n: Vec<_> = g
+      .neighbors8(Point::new(3, 0))
+      .map(|(p, d, _)| (p, d))
+      .collect();
+    assert_eq!(
+      n,
+      [(Point::new(4, 0), Dir::E), (Point::new(4, -1), Dir::NE)]
+    );
+    assert_eq!(g.remove(Point::new(4, -1)), Some('b'));
+    assert_eq!(g.neighbors4(Point::new(3, 0)).count(), 1);
+  }
+
+  #[test]
+  fn sparse_dense() {
+    let dense = Grid::new_from("#..\n.#.\n".to_string());
+    let rocks = SparseGrid::from_grid(&dense, |c| *c == '#');
+    assert_eq!(rocks.len(), 2);
+    assert_eq!(SparseGrid::from(&dense).len(), 6);
+
+    let (grid, origin) = rocks.to_grid('.');
+    assert_eq!(origin, Point::ORIGIN);
+    assert_eq!(grid, Grid::new_from("#.\n.#\n".to_string()));
+
+    let mut moved = SparseGrid::new();
+    moved.put(Point::new(-2, -5), 1);
+    moved.put(Point::new(-1, -4), 2);
+    let (grid, origin) = moved.to_grid(0);
+    assert_eq!(origin, Point::new(-2, -5));
+    assert_eq!(grid, Grid::new(vec![vec![1, 0], vec![0, 2]]));
+    assert_eq!(SparseGrid::<u8>::new().to_grid(0).0.height(), 0);
+  }
+}
@@ -0,0 +1,45 @@
+//! A one-shot local HTTP server standing in for adventofcode.com in tests
+
+use std::{
+  io::{BufRead, BufReader, Read, Write},
+  net::TcpListener,
+  sync::mpsc,
+  thread,
+};
+
+/// Serves a single canned response, handing back the request it received
+pub fn serve(status: u16, body: String) -> (String, mpsc::Receiver<String>) {
+  let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+  let url = format!("http://{}", listener.local_addr().unwrap());
+  let (tx, rx) = mpsc::channel();
+  thread::spawn(move || {
+    let (stream, _) = listener.accept().unwrap();
+    let mut reader = BufReader::new(stream);
+    let mut request = String::new();
+    let mut len = 0;
+    loop {
+      let mut line = String::new();
+      reader.read_line(&mut line).unwrap();
+      if let Some(v) = line.to_lowercase().strip_prefix("content-length:") {
+        len = v.trim().parse().unwrap();
+      }
+      request.push_str(&line);
+      if line == "\r\n" {
+        break;
+      }
+    }
+    let mut form = vec![0; len];
+    reader.read_exact(&mut form).unwrap();
+    request.push_str(&String::from_utf8(form).unwrap());
+    write!(
+      reader.get_mut(),
+      "HTTP/1.1 {} Stand-in\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+      status,
+      body.len(),
+      body
+    )
+    .unwrap();
+    tx.send(request).unwrap();
+  });
+  (url, rx)
+}
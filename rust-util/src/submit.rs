@@ -0,0 +1,166 @@
+use std::{error::Error, fmt::Display, time::Duration};
+
+use regex::Regex;
+
+use crate::{
+  answers::{Answer, AnswerStore, Hint},
+  client::AocClient,
+  AocDay, Part,
+};
+
+/// What adventofcode.com made of a submitted answer
+#[derive(Clone, Debug, PartialEq)]
+pub enum Submission {
+  Correct,
+  TooHigh,
+  TooLow,
+  /// Wrong, without a hint as to which way
+  Wrong,
+  RateLimited {
+    wait: Duration,
+  },
+  /// The part was already solved, so the answer was not checked
+  AlreadySolved,
+}
+
+impl Display for Submission {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    match self {
+      Submission::Correct => write!(f, "Correct!"),
+      Submission::TooHigh => write!(f, "Wrong, too high"),
+      Submission::TooLow => write!(f, "Wrong, too low"),
+      Submission::Wrong => write!(f, "Wrong"),
+      Submission::RateLimited { wait } => write!(f, "Rate limited, wait {:?}", wait),
+      Submission::AlreadySolved => write!(f, "Already solved"),
+    }
+  }
+}
+
+/// Reads the verdict out of the `<article>` of an answer response page
+pub fn parse_response(html: &str) -> Result<Submission, Box<dyn Error>> {
+  let article = Regex::new(r"(?s)<article>(.*?)</article>")?
+    .captures(html)
+    .map(|c| c[1].to_string())
+    .ok_or("No <article> in answer response")?;
+  let text = Regex::new(r"<[^>]*>")?.replace_all(&article, "");
+
+  if text.contains("That's the right answer") {
+    return Ok(Submission::Correct);
+  }
+  if text.contains("Did you already complete it") {
+    return Ok(Submission::AlreadySolved);
+  }
+  if text.contains("You gave an answer too recently") {
+    let wait = Regex::new(r"(?:(\d+)m )?(\d+)s left to wait")?
+      .captures(&text)
+      .map(|c| {
+        let mins = c.get(1).map_or(Ok(0), |m| m.as_str().parse::<u64>())?;
+        let secs = c[2].parse::<u64>()?;
+        Ok::<_, std::num::ParseIntError>(Duration::from_secs(mins * 60 + secs))
+      })
+      .transpose()?
+      .unwrap_or_default();
+    return Ok(Submission::RateLimited { wait });
+  }
+  if text.contains("That's not the right answer") {
+    return Ok(match text {
+      t if t.contains("your answer is too high") => Submission::TooHigh,
+      t if t.contains("your answer is too low") => Submission::TooLow,
+      _ => Submission::Wrong,
+    });
+  }
+  Err(format!("Unrecognized answer response: {}", text.trim()).into())
+}
+
+/// Submits an answer, recording it in `store` when it is accepted and
+/// remembering it as a wrong guess otherwise. Answers that earlier guesses
+/// already rule out are refused without contacting the site.
+pub fn submit(
+  client: &AocClient,
+  store: &AnswerStore,
+  day: AocDay,
+  part: Part,
+  answer: &Answer,
+) -> Result<Submission, Box<dyn Error>> {
+  if let Some(reason) = store.load(day)?.guesses(part).rule_out(answer) {
+    return Err(format!("Refusing to submit: {}", reason).into());
+  }
+  let res = parse_response(&client.post_answer(day, part, &answer.to_string())?)?;
+  match res {
+    Submission::Correct => store.record(day, part, answer, true)?,
+    Submission::TooHigh => store.record_guess(day, part, answer, Hint::TooHigh)?,
+    Submission::TooLow => store.record_guess(day, part, answer, Hint::TooLow)?,
+    Submission::Wrong => store.record_guess(day, part, answer, Hint::None)?,
+    Submission::RateLimited { .. } | Submission::AlreadySolved => (),
+  }
+  Ok(res)
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::stand_in;
+
+  fn page(msg: &str) -> String {
+    format!(
+      "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+      msg
+    )
+  }
+
+  #[test]
+  fn parses_responses() {
+    let cases = [
+      ("That's the right answer! You are one gold star closer.", Submission::Correct),
+      ("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; please wait one minute before trying again. <a href=\"/2024/day/3\">[Return to Day 3]</a>", Submission::TooHigh),
+      ("That's not the right answer; your answer is too low.", Submission::TooLow),
+      ("That's not the right answer.  If you're stuck, make sure you're using the full input data.", Submission::Wrong),
+      ("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 32s left to wait.", Submission::RateLimited { wait: Duration::from_secs(272) }),
+      ("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 45s left to wait.", Submission::RateLimited { wait: Duration::from_secs(45) }),
+      ("You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2024/day/3\">[Return to Day 3]</a>", Submission::AlreadySolved),
+    ];
+    for (msg, expected) in cases {
+      assert_eq!(parse_response(&page(msg)).unwrap(), expected, "{}", msg);
+    }
+    assert!(parse_response("<html>Please log in</html>").is_err());
+  }
+
+  #[test]
+  fn submits_and_records() {
+    let root = std::env::temp_dir().join(format!("aoc-submit-{}", std::process::id()));
+    let store = AnswerStore::new(&root);
+    let day = AocDay::D(2024, 3);
+
+    let (url, req) = stand_in::serve(
+      200,
+      page("That's not the right answer; your answer is too low."),
+    );
+    let client = AocClient::new(&url, "s3cr3t")
+      .unwrap()
+      .with_min_interval(Duration::ZERO);
+    let res = submit(&client, &store, day, Part::Two, &Answer::Int(41)).unwrap();
+    let req = req.recv().unwrap();
+    assert_eq!(res, Submission::TooLow);
+    assert!(req.starts_with("POST /2024/day/3/answer "));
+    assert!(req.contains("cookie: session=s3cr3t"));
+    assert!(req.ends_with("level=2&answer=41"));
+    assert_eq!(store.load(day).unwrap().part2, None);
+    assert_eq!(store.load(day).unwrap().guesses2.too_low, Some(41));
+
+    // Ruled out by the last guess, so this never reaches the (absent) server
+    let err = submit(&client, &store, day, Part::Two, &Answer::Int(40)).unwrap_err();
+    assert!(err.to_string().contains("too low"));
+
+    let (url, _req) = stand_in::serve(200, page("That's the right answer!"));
+    let client = AocClient::new(&url, "s3cr3t")
+      .unwrap()
+      .with_min_interval(Duration::ZERO);
+    assert_eq!(
+      submit(&client, &store, day, Part::Two, &Answer::Int(42)).unwrap(),
+      Submission::Correct
+    );
+    let answers = store.load(day).unwrap();
+    std::fs::remove_dir_all(&root).unwrap();
+    assert_eq!(answers.part2, Some(Answer::Int(42)));
+  }
+}
@@ -1,5 +1,5 @@
-use rust_util::{ Day};
-use std::{error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::error::Error;
 
 pub struct Solve {
   numbers: Vec<i32>,
@@ -18,25 +18,25 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     for (idx, v) in self.numbers.iter().enumerate() {
       for ov in &self.numbers[idx + 1..] {
         let sum = v + ov;
         if sum == 2020 {
-          return Ok(Box::new(format!("{}", v * ov)));
+          return Ok(Answer::from(v * ov));
         }
       }
     }
     Err("No Solution Found".into())
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     for (idx, v) in self.numbers.iter().enumerate() {
       for (odx, ov) in self.numbers[idx + 1..].iter().enumerate() {
         for ev in &self.numbers[odx + 1..] {
           let sum = v + ov + ev;
           if sum == 2020 {
-            return Ok(Box::new(format!("{}", v * ov * ev)));
+            return Ok(Answer::from(v * ov * ev));
           }
         }
       }
@@ -1,7 +1,6 @@
-use rust_util::{ Day};
+use rust_util::{Answer, Day};
 use std::collections::HashMap;
 use std::error::Error;
-use std::fmt::Display;
 
 pub struct Solve {
   jolts: Vec<u64>,
@@ -24,7 +23,7 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let mut c1 = 0;
     let mut c3 = 0;
     let mut prev = 0;
@@ -36,10 +35,10 @@ impl Day for Solve {
       }
       prev = *i;
     }
-    Ok(Box::new((c1 * c3).to_string()))
+    Ok(Answer::from(c1 * c3))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     fn search(jolts: &[u64], memo: &mut HashMap<u64, u64>) -> u64 {
       match jolts.split_first() {
         None => 0,          // Unreachable
@@ -59,8 +58,8 @@ impl Day for Solve {
           .sum(),
       }
     }
-    Ok(Box::new(
-      search(&self.jolts, &mut HashMap::new()).to_string(),
+    Ok(Answer::from(
+      search(&self.jolts, &mut HashMap::new()),
     ))
   }
 }
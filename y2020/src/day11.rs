@@ -1,6 +1,5 @@
-use rust_util::{ Day};
+use rust_util::{Answer, Day};
 use std::error::Error;
-use std::fmt::Display;
 use std::str::FromStr;
 
 pub struct Solve {
@@ -47,17 +46,17 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let tile_update = update_tile(4, find_adjacent);
-    Ok(Box::new(
-      run_sim(&self.tiles, self.width, &tile_update).to_string(),
+    Ok(Answer::from(
+      run_sim(&self.tiles, self.width, &tile_update),
     ))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let tile_update = update_tile(5, find_first_non_floor);
-    Ok(Box::new(
-      run_sim(&self.tiles, self.width, &tile_update).to_string(),
+    Ok(Answer::from(
+      run_sim(&self.tiles, self.width, &tile_update),
     ))
   }
 }
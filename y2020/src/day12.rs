@@ -1,6 +1,5 @@
-use rust_util::{ Day};
+use rust_util::{Answer, Day};
 use std::error::Error;
-use std::fmt::Display;
 use std::str::FromStr;
 
 #[derive(Debug, PartialEq)]
@@ -63,7 +62,7 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let mut dir = Direction::East;
     let (mut x, mut y) = (0, 0);
     for act in self.actions.iter() {
@@ -94,10 +93,10 @@ impl Day for Solve {
         Action::W(amt) => x -= amt,
       }
     }
-    Ok(Box::new((x.abs() + y.abs()).to_string()))
+    Ok(Answer::from(x.abs() + y.abs()))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let (mut ship_x, mut ship_y) = (0, 0);
     let (mut wp_x, mut wp_y) = (10, 1);
     for act in self.actions.iter() {
@@ -122,7 +121,7 @@ impl Day for Solve {
         Action::W(amt) => wp_x -= amt,
       }
     }
-    Ok(Box::new((ship_x.abs() + ship_y.abs()).to_string()))
+    Ok(Answer::from(ship_x.abs() + ship_y.abs()))
   }
 }
 
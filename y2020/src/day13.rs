@@ -1,5 +1,5 @@
-use rust_util::{ Day};
-use std::{error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::error::Error;
 
 pub struct Solve {
   target: usize,
@@ -28,7 +28,7 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let ans = self
       .buses
       .iter()
@@ -36,10 +36,10 @@ impl Day for Solve {
       .min_by(|a, b| a.1.cmp(&b.1))
       .map(|(bus_id, time)| bus_id * (time - self.target))
       .unwrap();
-    Ok(Box::new(ans.to_string()))
+    Ok(Answer::from(ans))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     // Can't claim this one as mine :/ Not a numberphile, so the concept of
     // Chinese Remainder Theorem is new to me
     let (ans, _) = self
@@ -56,6 +56,6 @@ impl Day for Solve {
           .unwrap();
         (sol, step * bus_id)
       });
-    Ok(Box::new(ans.to_string()))
+    Ok(Answer::from(ans))
   }
 }
@@ -1,7 +1,6 @@
-use rust_util::{ Day};
+use rust_util::{Answer, Day};
 use std::collections::HashMap;
 use std::error::Error;
-use std::fmt::Display;
 
 pub struct Solve {
   program: Vec<Op>,
@@ -59,21 +58,19 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(
       run_program(&self.program, |mask, adr, val, mem| {
         mem.insert(adr, val & mask.ones | mask.zero);
-      })
-      .to_string(),
+      }),
     ))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(
       run_program(&self.program, |mask, adr, val, mut mem| {
         update_memory(mask.flow, adr | mask.zero, val, &mut mem)
-      })
-      .to_string(),
+      }),
     ))
   }
 }
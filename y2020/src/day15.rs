@@ -1,5 +1,5 @@
-use rust_util::{ Day};
-use std::{error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::error::Error;
 
 pub struct Solve {
   numbers: Vec<usize>,
@@ -20,12 +20,12 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(play_game(2020, &self.numbers).to_string()))
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(play_game(2020, &self.numbers)))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(play_game(30000000, &self.numbers).to_string()))
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(play_game(30000000, &self.numbers)))
   }
 }
 
@@ -3,8 +3,7 @@ use regex::Captures;
 use regex::Regex;
 
 use core::panic;
-use rust_util::{ Day};
-use std::fmt::Display;
+use rust_util::{Answer, Day};
 use std::{collections::HashSet, error::Error, ops::RangeInclusive};
 
 lazy_static! {
@@ -70,8 +69,8 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(
       self
         .other_tickets
         .iter()
@@ -83,12 +82,11 @@ impl Day for Solve {
             .iter()
             .any(|rule| rule.lower.contains(v) || rule.upper.contains(v))
         })
-        .sum::<u64>()
-        .to_string(),
+        .sum::<u64>(),
     ))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let mut valid_tickets: Vec<Vec<u64>> = self
       .other_tickets
       .iter()
@@ -157,6 +155,6 @@ impl Day for Solve {
       .map(|(_, i)| self.my_ticket[*i])
       .product::<u64>();
 
-    Ok(Box::new(ans.to_string()))
+    Ok(Answer::from(ans))
   }
 }
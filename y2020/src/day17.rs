@@ -1,8 +1,7 @@
-use rust_util::{ Day};
+use rust_util::{Answer, Day};
 use std::{
   collections::{HashMap, HashSet},
   error::Error,
-  fmt::Display,
   str::FromStr,
 };
 
@@ -71,31 +70,29 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let mut state = self.state.clone();
     for _ in 0..6 {
       state = update_state(&state, expand_3d);
     }
-    Ok(Box::new(
+    Ok(Answer::from(
       state
         .values()
         .filter(|c| **c == Cell::ACTIVE)
-        .count()
-        .to_string(),
+        .count(),
     ))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let mut state = self.state.clone();
     for _ in 0..6 {
       state = update_state(&state, expand_4d);
     }
-    Ok(Box::new(
+    Ok(Answer::from(
       state
         .values()
         .filter(|c| **c == Cell::ACTIVE)
-        .count()
-        .to_string(),
+        .count(),
     ))
   }
 }
@@ -1,5 +1,5 @@
-use rust_util::{ Day};
-use std::{error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::error::Error;
 
 #[derive(Clone, Debug, PartialEq)]
 enum Token {
@@ -37,7 +37,7 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let ans: i64 = self
       .input
       .lines()
@@ -50,10 +50,10 @@ impl Day for Solve {
       })
       .map(|exp| evaluate(&exp))
       .sum();
-    Ok(Box::new(ans.to_string()))
+    Ok(Answer::from(ans))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let ans: i64 = self
       .input
       .lines()
@@ -66,7 +66,7 @@ impl Day for Solve {
       })
       .map(|exp| evaluate(&exp))
       .sum();
-    Ok(Box::new(ans.to_string()))
+    Ok(Answer::from(ans))
   }
 }
 
@@ -1,6 +1,6 @@
 use regex::Regex;
-use rust_util::{ Day};
-use std::{collections::HashMap, error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::{collections::HashMap, error::Error};
 
 type Rules = HashMap<String, String>;
 
@@ -31,11 +31,11 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(solve(&self.rules, &self.messages).to_string()))
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(solve(&self.rules, &self.messages)))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let (mut ans, mut nxt) = (0, 1);
     let mut reps = 1;
     while ans != nxt {
@@ -46,7 +46,7 @@ impl Day for Solve {
       nxt = solve(&rules, &self.messages);
       reps += 1;
     }
-    Ok(Box::new(ans.to_string()))
+    Ok(Answer::from(ans))
   }
 }
 
@@ -1,6 +1,6 @@
-use rust_util::{Day};
+use rust_util::{Answer, Day};
 
-use std::{error::Error, fmt::Display};
+use std::error::Error;
 
 pub struct Solve {
   input: Vec<PasswordEntry>,
@@ -48,8 +48,8 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(
       self
         .input
         .iter()
@@ -57,13 +57,12 @@ impl Day for Solve {
           let cnt = pwd.pass.matches(pwd.ch).count();
           ((pwd.bounds.0)..(pwd.bounds.1) + 1).contains(&cnt)
         })
-        .count()
-        .to_string(),
+        .count(),
     ))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(
       self
         .input
         .iter()
@@ -77,8 +76,7 @@ impl Day for Solve {
             .xor(chars.nth(upper).filter(|s| s == &pwd.ch))
             .is_some()
         })
-        .count()
-        .to_string(),
+        .count(),
     ))
   }
 }
@@ -1,7 +1,6 @@
-use rust_util::{Day};
+use rust_util::{Answer, Day};
 use std::collections::HashMap;
 use std::error::Error;
-use std::fmt::Display;
 
 type Img = Vec<String>;
 
@@ -70,15 +69,15 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let checksum = self.grid[0][0].id
       * self.grid[0][self.sides - 1].id
       * self.grid[self.sides - 1][0].id
       * self.grid[self.sides - 1][self.sides - 1].id;
-    Ok(Box::new(checksum.to_string()))
+    Ok(Answer::from(checksum))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let tile_size = self.grid[0][0].img.len();
     let mut img = Vec::new();
     for r in 0..self.sides {
@@ -92,13 +91,12 @@ impl Day for Solve {
     }
 
     let monsters = scan_image(&img);
-    Ok(Box::new(
-      (img
+    Ok(Answer::from(
+      img
         .iter()
         .map(|v| v.chars().filter(|c| *c == '#').count())
         .sum::<usize>()
-        - monsters * 15)
-        .to_string(),
+        - monsters * 15,
     ))
   }
 }
@@ -1,6 +1,6 @@
-use rust_util::{Day};
+use rust_util::{Answer, Day};
 
-use std::{error::Error, fmt::Display};
+use std::error::Error;
 
 const TREE: u8 = b'#';
 
@@ -19,17 +19,16 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(trees_hit(&self, 3, 1).to_string()))
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(trees_hit(&self, 3, 1)))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(
       [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
         .iter()
         .map(|(right, down)| trees_hit(self, *right, *down))
-        .product::<usize>()
-        .to_string(),
+        .product::<usize>(),
     ))
   }
 }
@@ -1,11 +1,10 @@
 extern crate regex;
 use regex::Regex;
 
-use rust_util::{ Day};
+use rust_util::{Answer, Day};
 
 use std::collections::HashMap;
 use std::error::Error;
-use std::fmt::Display;
 use std::ops::RangeInclusive;
 
 const REQUIRED_KEYS: [&str; 7] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
@@ -45,26 +44,24 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(
       self
         .passports
         .iter()
         .filter(has_required_fields)
-        .count()
-        .to_string(),
+        .count(),
     ))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(
       self
         .passports
         .iter()
         .filter(has_required_fields)
         .filter(has_valid_fields)
-        .count()
-        .to_string(),
+        .count(),
     ))
   }
 }
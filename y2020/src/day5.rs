@@ -1,5 +1,5 @@
-use rust_util::{Day};
-use std::{error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::error::Error;
 
 pub struct Solve {
   seats: Vec<i32>,
@@ -19,28 +19,28 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let ans = self
       .seats
       .last()
-      .map(|s| s.to_string())
+      .copied()
       .ok_or("No Seats Parsed".into());
     match ans {
-      Ok(v) => Ok(Box::new(v)),
+      Ok(v) => Ok(Answer::from(v)),
       Err(e) => Err(e),
     }
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let ans = self
       .seats
       .windows(3)
       .filter(|win| win[0] + 1 != win[1] || win[1] + 1 != win[2])
       .next()
-      .map(|win| (win[1] + 1).to_string())
+      .map(|win| win[1] + 1)
       .ok_or("No Missing Seat Found".into());
     match ans {
-      Ok(v) => Ok(Box::new(v)),
+      Ok(v) => Ok(Answer::from(v)),
       Err(e) => Err(e),
     }
   }
@@ -1,7 +1,6 @@
-use rust_util::{Day};
+use rust_util::{Answer, Day};
 use std::collections::HashMap;
 use std::error::Error;
-use std::fmt::Display;
 
 pub struct Solve {
   answers: Vec<(i32, HashMap<char, i32>)>,
@@ -30,23 +29,21 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let result = self
       .answers
       .iter()
       .map(|(_, map)| map.len())
-      .sum::<usize>()
-      .to_string();
-    Ok(Box::new(result))
+      .sum::<usize>();
+    Ok(Answer::from(result))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let result = self
       .answers
       .iter()
       .map(|(group_size, map)| map.values().filter(|ct| **ct == *group_size).count())
-      .sum::<usize>()
-      .to_string();
-    Ok(Box::new(result))
+      .sum::<usize>();
+    Ok(Answer::from(result))
   }
 }
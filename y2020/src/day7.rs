@@ -1,7 +1,6 @@
-use rust_util::{ Day};
+use rust_util::{Answer, Day};
 use std::collections::HashSet;
 use std::error::Error;
-use std::fmt::Display;
 
 // A graph might be better suited to this problem, but modeling
 // that in rust is surprisingly non-trivial. TIL.
@@ -50,7 +49,7 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let mut result: HashSet<String> = HashSet::new();
     let mut frontier: Vec<String> = vec!["shiny gold".to_string()];
     while !frontier.is_empty() {
@@ -65,10 +64,10 @@ impl Day for Solve {
         }
       }
     }
-    Ok(Box::new(result.len().to_string()))
+    Ok(Answer::from(result.len()))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let shiny_form = match self.formulas.iter().find(|f| f.result.name == "shiny gold") {
       None => return Err("Couldn't find shiny gold formula".into()),
       Some(form) => form,
@@ -95,11 +94,7 @@ impl Day for Solve {
       }
       result += 1;
     }
-    Ok(Box::new(result.to_string()))
-  }
-
-  fn run(&self) -> Result<String, Box<dyn Error>> {
-    Ok(format!("Part1: {}\nPart2: {}", self.p1()?, self.p2()?))
+    Ok(Answer::from(result))
   }
 }
 
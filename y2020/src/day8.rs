@@ -1,7 +1,6 @@
-use rust_util::{Day};
+use rust_util::{Answer, Day};
 use std::collections::HashSet;
 use std::error::Error;
-use std::fmt::Display;
 use std::str::FromStr;
 
 pub struct Solve {
@@ -58,14 +57,14 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     match run_tape(&self.tape) {
-      Terminate::LOOP(amt) => Ok(Box::new(amt.to_string())),
+      Terminate::LOOP(amt) => Ok(Answer::from(amt)),
       _ => Err("No loop found".into()),
     }
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let ans = self
       .tape
       .repeat(self.tape.len())
@@ -80,15 +79,14 @@ impl Day for Solve {
         }
         vec![(idx, chunk[idx], chunk.to_owned()), (idx, alt[idx], alt)]
       })
-      .map(|(idx, ins, t)| (idx, ins, run_tape(&t.to_vec())))
-      .find_map(|(idx, ins, res)| match res {
-        Terminate::NORMAL(amt) => Some((idx, ins, amt)),
+      .map(|(_, _, t)| run_tape(&t.to_vec()))
+      .find_map(|res| match res {
+        Terminate::NORMAL(amt) => Some(amt),
         _ => None,
       })
-      .map(|(idx, ins, acc)| format!("Acc {} - INS {} to {:?}", acc, idx, ins))
       .ok_or("Did not find normal termination".into());
     match ans {
-      Ok(v) => Ok(Box::new(v)),
+      Ok(v) => Ok(Answer::from(v)),
       Err(e) => Err(e),
     }
   }
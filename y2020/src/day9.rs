@@ -1,5 +1,5 @@
-use rust_util::{Day};
-use std::{error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::error::Error;
 
 pub struct Solve {
   data: Vec<i32>,
@@ -20,21 +20,21 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    match find_no_sum_num(&self.data).map(|v| v.to_string()) {
-      Some(ans) => Ok(Box::new(format!("{}", ans))),
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+    match find_no_sum_num(&self.data) {
+      Some(ans) => Ok(Answer::from(ans)),
       None => Err("No solve found".into()),
     }
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let target = find_no_sum_num(&self.data).ok_or("No invalid nums found")?;
     let sum_list = (0..self.data.len())
       .into_iter()
       .find_map(|f| has_contiguous_sum(&self.data[f..], target))
       .ok_or("No solution found")?;
-    Ok(Box::new(
-      (sum_list.iter().min().unwrap() + sum_list.iter().max().unwrap()).to_string(),
+    Ok(Answer::from(
+      sum_list.iter().min().unwrap() + sum_list.iter().max().unwrap(),
     ))
   }
 }
@@ -0,0 +1,7 @@
+#[macro_use]
+extern crate lazy_static;
+extern crate rust_util;
+
+use rust_util_macro::import_aoc_solutions;
+
+import_aoc_solutions!();
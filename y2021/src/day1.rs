@@ -1,6 +1,6 @@
 use itertools::Itertools;
-use rust_util::Day;
-use std::{error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::error::Error;
 
 pub struct Solve {
   input: String,
@@ -15,8 +15,8 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(
       self
         .input
         .lines()
@@ -27,8 +27,8 @@ impl Day for Solve {
     ))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(
       self
         .input
         .lines()
@@ -1,5 +1,5 @@
-use rust_util::{Day};
-use std::{collections::HashSet, error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::{collections::HashSet, error::Error};
 
 pub struct Solve {
   input: String,
@@ -17,7 +17,7 @@ impl TryFrom<String> for Solve {
 // Part 2: 3969823589
 // Elapsed: 306.58µs
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let opens: HashSet<char> = HashSet::from_iter(vec!['{', '[', '(', '<']);
     let mut score = 0;
     for line in self.input.lines() {
@@ -53,10 +53,10 @@ impl Day for Solve {
         }
       }
     }
-    Ok(Box::new(score))
+    Ok(Answer::from(score))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let opens: HashSet<char> = HashSet::from_iter(vec!['{', '[', '(', '<']);
 
     let mut scores: Vec<u64> = Vec::new();
@@ -100,7 +100,7 @@ impl Day for Solve {
       }
     }
     scores.sort();
-    Ok(Box::new(scores[scores.len() / 2]))
+    Ok(Answer::from(scores[scores.len() / 2]))
   }
 }
 
@@ -1,5 +1,5 @@
-use rust_util::{Day};
-use std::{error::Error, fmt::Display, thread, time::Duration};
+use rust_util::{Answer, Day};
+use std::{error::Error, thread, time::Duration};
 
 pub struct Solve {
   p1: usize,
@@ -51,12 +51,12 @@ impl TryFrom<String> for Solve {
 
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(self.p1))
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(self.p1))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(self.p2))
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(self.p2))
   }
 }
 
@@ -1,8 +1,7 @@
-use rust_util::{Day};
+use rust_util::{Answer, Day};
 use std::{
   collections::{HashMap, HashSet},
   error::Error,
-  fmt::Display,
 };
 
 pub struct Solve {
@@ -59,14 +58,14 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(
       explore(&self.nodes, &mut init_frontier(&self.nodes, true)).len(),
     ))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(
       explore(&self.nodes, &mut init_frontier(&self.nodes, false)).len(),
     ))
   }
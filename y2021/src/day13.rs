@@ -1,6 +1,6 @@
 use itertools::Itertools;
-use rust_util::{Day};
-use std::{collections::HashMap, error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::{collections::HashMap, error::Error};
 
 type Coord = (usize, usize);
 type Paper = HashMap<Coord, u8>;
@@ -51,11 +51,11 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(fold_paper(&self.paper, &self.folds[0..1]).len()))
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(fold_paper(&self.paper, &self.folds[0..1]).len()))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let paper = fold_paper(&self.paper, &self.folds[..]);
     let max_x = paper.keys().map(|(x, _)| x).max().unwrap_or(&0);
     let output = paper
@@ -75,7 +75,7 @@ impl Day for Solve {
       .sorted()
       .map(|(_, v)| v)
       .join("\n");
-    Ok(Box::new(format!("\n{}", output)))
+    Ok(Answer::from(output))
   }
 }
 
@@ -1,6 +1,6 @@
 use itertools::{Itertools, MinMaxResult};
-use rust_util::{Day};
-use std::{collections::HashMap, error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::{collections::HashMap, error::Error};
 
 type Element = char;
 type Polymer = (Element, Element);
@@ -66,14 +66,14 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let (min, max) = expand(&self.template, &self.rules, &self.ends, 10);
-    Ok(Box::new(max - min))
+    Ok(Answer::from((max - min) as u64))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let (min, max) = expand(&self.template, &self.rules, &self.ends, 40);
-    Ok(Box::new(max - min))
+    Ok(Answer::from((max - min) as u64))
   }
 }
 
@@ -1,9 +1,8 @@
-use rust_util::{Day};
+use rust_util::{Answer, Day};
 use std::{
   cmp::Ordering,
   collections::{BinaryHeap, HashMap},
   error::Error,
-  fmt::Display,
 };
 
 const NEIGHBORS: [(isize, isize); 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
@@ -58,18 +57,18 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let (nodes, goal) = build_map(&self.tile, self.width, self.height, 1);
     match find_path(&nodes, &goal) {
-      Some((_, cost)) => Ok(Box::new(cost)),
+      Some((_, cost)) => Ok(Answer::from(cost)),
       None => Err("No solve found".into()),
     }
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let (nodes, goal) = build_map(&self.tile, self.width, self.height, 5);
     match find_path(&nodes, &goal) {
-      Some((_, cost)) => Ok(Box::new(cost)),
+      Some((_, cost)) => Ok(Answer::from(cost)),
       None => Err("No solve found".into()),
     }
   }
@@ -1,5 +1,5 @@
-use rust_util::{Day};
-use std::{error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::error::Error;
 
 pub struct Solve {
   tape: String,
@@ -166,15 +166,15 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let tape = self.tape.clone();
     let mut bits = tape.chars();
-    Ok(Box::new(sum_versions(&parse_packet(&mut bits))))
+    Ok(Answer::from(sum_versions(&parse_packet(&mut bits))))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let tape = self.tape.clone();
     let mut bits = tape.chars();
-    Ok(Box::new(eval(&parse_packet(&mut bits))))
+    Ok(Answer::from(eval(&parse_packet(&mut bits))))
   }
 }
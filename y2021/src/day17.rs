@@ -1,5 +1,5 @@
-use rust_util::{Day};
-use std::{error::Error, fmt::Display, ops::RangeInclusive};
+use rust_util::{Answer, Day};
+use std::{error::Error, ops::RangeInclusive};
 
 pub struct Solve {
   max: (i32, (i32, i32)),
@@ -42,12 +42,12 @@ impl TryFrom<String> for Solve {
 
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(format!("{} {:?}", self.max.0, self.max.1)))
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(self.max.0))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(self.hits))
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(self.hits))
   }
 }
 
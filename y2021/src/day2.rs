@@ -1,6 +1,6 @@
 use itertools::Itertools;
-use rust_util::Day;
-use std::{error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::error::Error;
 
 pub struct Solve {
   input: String,
@@ -15,7 +15,7 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let (x, y) = self
       .input
       .lines()
@@ -28,10 +28,10 @@ impl Day for Solve {
         _ => unreachable!("Invalid direction"),
       });
 
-    Ok(Box::new(x * y))
+    Ok(Answer::from(x * y))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let (_, x, y) = self
       .input
       .lines()
@@ -44,6 +44,6 @@ impl Day for Solve {
         _ => unreachable!("Invalid direction"),
       });
 
-    Ok(Box::new(x * y))
+    Ok(Answer::from(x * y))
   }
 }
@@ -1,5 +1,5 @@
-use rust_util::{Day};
-use std::{error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::error::Error;
 
 pub struct Solve {
   input: Vec<u32>,
@@ -24,7 +24,7 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let majority = majority(&self.input);
     let gamma = (0..self.bit_len)
       .map(|shift| self.mask_base >> shift)
@@ -35,13 +35,13 @@ impl Day for Solve {
           gamma
         }
       });
-    Ok(Box::new(gamma * (!gamma & (1 << self.bit_len) - 1)))
+    Ok(Answer::from(gamma * (!gamma & (1 << self.bit_len) - 1)))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let oxygen = comp(true, self.input.clone(), self.bit_len, self.mask_base);
     let co2 = comp(false, self.input.clone(), self.bit_len, self.mask_base);
-    Ok(Box::new(oxygen * co2))
+    Ok(Answer::from(oxygen * co2))
   }
 }
 
@@ -1,9 +1,8 @@
 use itertools::Itertools;
-use rust_util::{Day};
+use rust_util::{Answer, Day};
 use std::{
   collections::{HashMap, HashSet},
   error::Error,
-  fmt::Display,
 };
 
 const BINGOS: [u32; 10] = [
@@ -96,7 +95,7 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     self
       .calls
       .iter()
@@ -108,13 +107,13 @@ impl Day for Solve {
         let new_score = boards
           .iter()
           .find_map(|board| board.maybe_bingo().map(|b| b.score(call)));
-        (boards, new_score.map(|s| Box::new(s) as Box<dyn Display>))
+        (boards, new_score.map(Answer::from))
       })
       .1
       .ok_or::<Box<dyn Error>>("No Solve Found".into())
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let mut boards_in_play = self.boards.iter().map(|b| b.id).collect::<HashSet<usize>>();
     let mut boards = self.boards.clone();
     for call in &self.calls {
@@ -125,7 +124,7 @@ impl Day for Solve {
         board.call(call);
         if let Some(board) = board.maybe_bingo() {
           if boards_in_play.len() == 1 {
-            return Ok(Box::new(board.score(call)));
+            return Ok(Answer::from(board.score(call)));
           }
           boards_in_play.remove(&board.id);
         }
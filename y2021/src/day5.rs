@@ -1,5 +1,5 @@
-use rust_util::{Day};
-use std::{collections::HashMap, error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::{collections::HashMap, error::Error};
 
 pub struct Solve {
   segments: Vec<Segment>,
@@ -26,8 +26,8 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(count_overlaps(
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(count_overlaps(
       self
         .segments
         .iter()
@@ -35,8 +35,8 @@ impl Day for Solve {
     )))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(count_overlaps(self.segments.iter())))
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(count_overlaps(self.segments.iter())))
   }
 }
 
@@ -1,5 +1,5 @@
-use rust_util::{Day};
-use std::{collections::HashMap, error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::{collections::HashMap, error::Error};
 
 pub struct Solve {
   ans: (usize, usize),
@@ -32,12 +32,12 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(self.ans.0))
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(self.ans.0))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(self.ans.1))
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(self.ans.1))
   }
 }
 
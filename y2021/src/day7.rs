@@ -1,6 +1,6 @@
 use itertools::Itertools;
-use rust_util::{Day};
-use std::{error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::error::Error;
 
 pub struct Solve {
   positions: Vec<u32>,
@@ -37,19 +37,19 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let fuel_cost = |src: u32, dest: u32| (src as i32 - dest as i32).abs();
-    let (pos, cost) = self.run_search(fuel_cost);
-    Ok(Box::new(format!("{} @ {} fuel", pos, cost)))
+    let (_, cost) = self.run_search(fuel_cost);
+    Ok(Answer::from(cost))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let fuel_cost = |src: u32, dest: u32| {
       let n = (src as i32 - dest as i32).abs();
       (n * (n + 1)) / 2
     };
-    let (pos, cost) = self.run_search(fuel_cost);
-    Ok(Box::new(format!("{} @ {} fuel", pos, cost)))
+    let (_, cost) = self.run_search(fuel_cost);
+    Ok(Answer::from(cost))
   }
 }
 
@@ -1,6 +1,6 @@
 use itertools::Itertools;
-use rust_util::{Day};
-use std::{error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::error::Error;
 
 const NEIGHBORS: [(isize, isize); 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
 
@@ -23,7 +23,7 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let sum: u32 = self
       .lows
       .iter()
@@ -36,12 +36,12 @@ impl Day for Solve {
           .unwrap()
       })
       .sum();
-    Ok(Box::new(sum))
+    Ok(Answer::from(sum))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let mut field = self.field.clone();
-    Ok(Box::new(
+    Ok(Answer::from(
       self
         .lows
         .iter()
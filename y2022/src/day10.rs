@@ -1,5 +1,5 @@
-use rust_util::Day;
-use std::{error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::error::Error;
 
 pub struct Solve {
   instrs: Vec<Instr>,
@@ -41,7 +41,7 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let mut signal_strength = 0;
     let read_op = |cycle: i32, register: i32| {
       if cycle == 20 || (cycle - 20) % 40 == 0 {
@@ -50,10 +50,10 @@ impl Day for Solve {
     };
     simulate(&self.instrs, read_op);
 
-    Ok(Box::new(signal_strength))
+    Ok(Answer::from(signal_strength))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let mut crt = String::new();
     let read_op = |cycle: i32, register: i32| {
       let crt_pos = (cycle - 1) % 40;
@@ -72,7 +72,7 @@ impl Day for Solve {
       }
       formatted_crt += &ch.to_string();
     }
-    Ok(Box::new(formatted_crt))
+    Ok(Answer::from(formatted_crt))
   }
 }
 
@@ -1,7 +1,7 @@
 use itertools::Itertools;
-use rust_util::Day;
+use rust_util::{Answer, Day};
 use std::collections::VecDeque;
-use std::{error::Error, fmt::Display};
+use std::error::Error;
 
 type Worry = usize;
 pub struct Solve {
@@ -145,23 +145,19 @@ fn simulate(monkeys: &[Monkey], rounds: usize, reducer: impl Fn(Worry) -> Worry)
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(format!(
-      "{:?}",
-      simulate(&self.monkeys, 20, |inp| inp / 3)
-    )))
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(simulate(&self.monkeys, 20, |inp| inp / 3)))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let reducer = self
       .monkeys
       .iter()
       .map(|mk| mk.test.divide)
       .reduce(|acc, x| acc * x)
       .unwrap();
-    Ok(Box::new(format!(
-      "{:?}",
-      simulate(&self.monkeys, 10000, |inp| inp % reducer)
-    )))
+    Ok(Answer::from(simulate(&self.monkeys, 10000, |inp| {
+      inp % reducer
+    })))
   }
 }
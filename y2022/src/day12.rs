@@ -1,8 +1,7 @@
-use rust_util::Day;
+use rust_util::{Answer, Day};
 use std::{
   collections::{HashMap, HashSet, VecDeque},
   error::Error,
-  fmt::Display,
 };
 
 type Pnt = (usize, usize);
@@ -102,7 +101,7 @@ fn search(
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let (map, start, goal) = parse(self.value.to_owned(), false);
     let dist = search(map, start, |current_pos, _| {
       if current_pos == goal {
@@ -111,10 +110,10 @@ impl Day for Solve {
         None
       }
     });
-    Ok(Box::new(format!("{:?}", dist)))
+    Ok(Answer::from(dist))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let (map, start, _) = parse(self.value.to_owned(), true);
     let dist = search(map, start, |current_pos, current_h| {
       if current_h == 25 {
@@ -123,6 +122,6 @@ impl Day for Solve {
         None
       }
     });
-    Ok(Box::new(format!("{:?}", dist)))
+    Ok(Answer::from(dist))
   }
 }
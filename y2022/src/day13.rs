@@ -1,6 +1,6 @@
-use rust_util::Day;
+use rust_util::{Answer, Day};
 use serde_json::Value;
-use std::{cmp::Ordering, error::Error, fmt::Display};
+use std::{cmp::Ordering, error::Error};
 
 pub struct Solve {
   pairs: Vec<(String, String)>,
@@ -46,7 +46,7 @@ fn verify(left: Value, right: Value) -> Ordering {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let solve: usize = self
       .pairs
       .iter()
@@ -58,10 +58,10 @@ impl Day for Solve {
       })
       .map(|(i, _)| i + 1)
       .sum();
-    Ok(Box::new(format!("{}", solve)))
+    Ok(Answer::from(solve))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let mut pairs = self.pairs.clone();
     pairs.push(("[[2]]".into(), "[[6]]".into()));
     let mut packets: Vec<Value> = pairs
@@ -98,7 +98,7 @@ impl Day for Solve {
       })
       .reduce(|a, b| a * b);
 
-    Ok(Box::new(format!("{:?}", solve)))
+    Ok(Answer::from(solve.ok_or("no divider packets")?))
   }
 }
 
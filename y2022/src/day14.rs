@@ -1,6 +1,6 @@
 use itertools::Itertools;
-use rust_util::Day;
-use std::{collections::HashSet, error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::{collections::HashSet, error::Error};
 
 type Pnt = (usize, usize);
 pub struct Solve {
@@ -119,11 +119,11 @@ impl Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(self.simulate(true).to_string()))
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(self.simulate(true)))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(self.simulate(false).to_string()))
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(self.simulate(false)))
   }
 }
@@ -1,12 +1,11 @@
 use regex::Regex;
-use rust_util::Day;
-use std::{error::Error, fmt::Display};
+use rust_util::{Answer, Day, Point};
+use std::error::Error;
 
-type Pnt = (isize, isize);
 pub struct Solve {
-  pairs: Vec<(Pnt, Pnt)>,
-  row: isize,
-  max: isize,
+  pairs: Vec<(Point, Point)>,
+  row: i64,
+  max: i64,
 }
 
 impl TryFrom<String> for Solve {
@@ -22,16 +21,8 @@ impl TryFrom<String> for Solve {
         .lines()
         .map(|l| {
           let caps = regex.captures(l).unwrap();
-          (
-            (
-              caps.get(1).unwrap().as_str().parse().unwrap(),
-              caps.get(2).unwrap().as_str().parse().unwrap(),
-            ),
-            (
-              caps.get(3).unwrap().as_str().parse().unwrap(),
-              caps.get(4).unwrap().as_str().parse().unwrap(),
-            ),
-          )
+          let n = |i| caps.get(i).unwrap().as_str().parse().unwrap();
+          (Point::new(n(1), n(2)), Point::new(n(3), n(4)))
         })
         .collect(),
       row: 2000000,
@@ -41,8 +32,8 @@ impl TryFrom<String> for Solve {
 }
 
 struct Range {
-  pub start: isize,
-  pub end: isize,
+  pub start: i64,
+  pub end: i64,
 }
 
 impl Range {
@@ -50,36 +41,32 @@ impl Range {
     self.end >= other.start && other.end >= self.start
   }
 
-  fn contains(&self, n: isize) -> bool {
+  fn contains(&self, n: i64) -> bool {
     self.start <= n && n <= self.end
   }
 
-  fn covering(&self) -> isize {
+  fn covering(&self) -> i64 {
     self.end - self.start + 1
   }
 
   fn add(self, other: Range) -> Range {
     Range {
-      start: isize::min(self.start, other.start),
-      end: isize::max(self.end, other.end),
+      start: i64::min(self.start, other.start),
+      end: i64::max(self.end, other.end),
     }
   }
 }
 
-fn dist_to((xa, ya): &Pnt, (xb, yb): &Pnt) -> isize {
-  (xa - xb).abs() + (ya - yb).abs()
-}
-
-fn get_row_coverage(pairs: &Vec<(Pnt, Pnt)>, row: isize) -> Vec<Range> {
+fn get_row_coverage(pairs: &Vec<(Point, Point)>, row: i64) -> Vec<Range> {
   let mut ranges: Vec<Range> = Vec::new();
 
   for (sensor, beacon) in pairs {
     // Get how width the sensor is at this row
-    let mut new_r = match dist_to(sensor, beacon) - (sensor.1 - row).abs() {
+    let mut new_r = match sensor.manhattan(beacon) - (sensor.y - row).abs() {
       x if x < 0 => continue,
       width => Range {
-        start: sensor.0 - width,
-        end: sensor.0 + width,
+        start: sensor.x - width,
+        end: sensor.x + width,
       },
     };
 
@@ -100,16 +87,16 @@ fn get_row_coverage(pairs: &Vec<(Pnt, Pnt)>, row: isize) -> Vec<Range> {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let ans = get_row_coverage(&self.pairs, self.row)
       .iter()
       .map(|r| r.covering())
-      .sum::<isize>()
+      .sum::<i64>()
       - 1;
-    Ok(Box::new(format!("{:?}", ans)))
+    Ok(Answer::from(ans))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let ans = (0..=self.max)
       .map(|row| (row, get_row_coverage(&self.pairs, row)))
       // A row with a gap in it (not 1 big range) has a spot for a beacon
@@ -120,6 +107,6 @@ impl Day for Solve {
           .find(|x| !ranges.iter().any(|r| r.contains(*x)))
           .map(|x| x * 4_000_000 + y)
       });
-    Ok(Box::new(format!("{:?}", ans)))
+    Ok(Answer::from(ans.ok_or("no gap for the beacon")?))
   }
 }
@@ -3,11 +3,10 @@ use crossterm::{
   terminal::{self, Clear, ClearType},
   ExecutableCommand,
 };
-use rust_util::Day;
+use rust_util::{Answer, Day};
 use std::{
   collections::HashSet,
   error::Error,
-  fmt::Display,
   io::{Stdout, Write},
 };
 
@@ -145,13 +144,13 @@ fn simulate(std: &mut Option<Stdout>, jets: &Vec<char>, stop_at_count: usize) ->
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(simulate(&mut None, &self.jets, 2022).to_string()))
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(simulate(&mut None, &self.jets, 2022)))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     // TODO: Needs optimizing to complete this part for the full amount
-    Ok(Box::new(simulate(&mut None, &self.jets, 2022).to_string()))
+    Ok(Answer::from(simulate(&mut None, &self.jets, 2022)))
   }
 }
 
@@ -1,6 +1,6 @@
 use itertools::Itertools;
-use rust_util::Day;
-use std::{collections::HashSet, error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::{collections::HashSet, error::Error};
 
 type Coord = i8;
 type Bounds = (Coords, Coords);
@@ -117,28 +117,26 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(
       self
         .blocks
         .iter()
         .flat_map(Coords::neighbors)
         .filter(|c| !self.blocks.contains(c))
-        .count()
-        .to_string(),
+        .count(),
     ))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let air = ExposedBox::from(&self.blocks);
-    Ok(Box::new(
+    Ok(Answer::from(
       self
         .blocks
         .iter()
         .flat_map(Coords::neighbors)
         .filter(|c| air.contains(c))
-        .count()
-        .to_string(),
+        .count(),
     ))
   }
 }
@@ -1,5 +1,5 @@
-use rust_util::Day;
-use std::{error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::error::Error;
 
 pub struct Solve {
   pairs: Vec<(String, String)>,
@@ -25,7 +25,7 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let score: usize = self
       .pairs
       .iter()
@@ -48,10 +48,10 @@ impl Day for Solve {
         value + win
       })
       .sum();
-    Ok(Box::new(score))
+    Ok(Answer::from(score))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let score: usize = self
       .pairs
       .iter()
@@ -81,6 +81,6 @@ impl Day for Solve {
         _ => unreachable!(),
       })
       .sum();
-    Ok(Box::new(score))
+    Ok(Answer::from(score))
   }
 }
@@ -1,6 +1,6 @@
 use itertools::Itertools;
-use rust_util::Day;
-use std::{collections::VecDeque, error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::{collections::VecDeque, error::Error};
 
 type Bit = i64;
 pub struct Solve {
@@ -45,15 +45,15 @@ fn grove_position(buffer: &VecDeque<(usize, Bit)>) -> Bit {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let mut buffer = self.file.clone();
     for id in 0..buffer.len() {
       rotate(&id, &mut buffer);
     }
-    Ok(Box::new(grove_position(&buffer).to_string()))
+    Ok(Answer::from(grove_position(&buffer)))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let mut buffer = self
       .file
       .iter()
@@ -64,6 +64,6 @@ impl Day for Solve {
         rotate(&id, &mut buffer);
       }
     }
-    Ok(Box::new(grove_position(&buffer).to_string()))
+    Ok(Answer::from(grove_position(&buffer)))
   }
 }
@@ -1,5 +1,5 @@
-use rust_util::Day;
-use std::{collections::HashMap, error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::{collections::HashMap, error::Error};
 
 type Id = String;
 
@@ -138,14 +138,14 @@ fn has_humn(tree: &HashMap<Id, Operand>, id: &Id) -> bool {
 
 impl Day for Solve {
   // 142707821472432
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(
-      evaluate(&self.tree, &"root".to_string()).to_string(),
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(
+      evaluate(&self.tree, &"root".to_string()),
     ))
   }
 
   // 3587647562851
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let value = match self.tree.get("root").unwrap() {
       Operand::Num(_) => return Err("Not found".into()),
       Operand::Op(Operation { left, right, .. }) => match has_humn(&self.tree, left) {
@@ -153,6 +153,6 @@ impl Day for Solve {
         false => solve(&self.tree, right, evaluate(&self.tree, left)),
       },
     };
-    Ok(Box::new(value.to_string()))
+    Ok(Answer::from(value))
   }
 }
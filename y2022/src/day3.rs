@@ -1,5 +1,5 @@
-use rust_util::Day;
-use std::{collections::HashSet, error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::{collections::HashSet, error::Error};
 
 pub struct Solve {
   bags: Vec<String>,
@@ -16,7 +16,7 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let score: u32 = self
       .bags
       .iter()
@@ -37,10 +37,10 @@ impl Day for Solve {
         }
       })
       .sum();
-    Ok(Box::new(score))
+    Ok(Answer::from(score))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let score: u32 = self
       .bags
       .chunks_exact(3)
@@ -63,6 +63,6 @@ impl Day for Solve {
         }
       })
       .sum();
-    Ok(Box::new(score))
+    Ok(Answer::from(score))
   }
 }
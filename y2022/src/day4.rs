@@ -1,5 +1,5 @@
-use rust_util::Day;
-use std::{error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::error::Error;
 
 type Range = (usize, usize);
 pub struct Solve {
@@ -33,16 +33,16 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let fully_contained = self
       .pairs
       .iter()
       .filter(|((b1a, b1b), (b2a, b2b))| (b1a >= b2a && b1b <= b2b) || (b2a >= b1a && b2b <= b1b))
       .count();
-    Ok(Box::new(fully_contained))
+    Ok(Answer::from(fully_contained))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let partly_contained = self
       .pairs
       .iter()
@@ -53,6 +53,6 @@ impl Day for Solve {
           || (b1 <= a2 && b2 >= a2)
       })
       .count();
-    Ok(Box::new(partly_contained))
+    Ok(Answer::from(partly_contained))
   }
 }
@@ -1,6 +1,6 @@
 use regex::Regex;
-use rust_util::Day;
-use std::{collections::HashMap, error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::{collections::HashMap, error::Error};
 
 type CargoId = char;
 type StackId = usize;
@@ -81,7 +81,7 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let mut cargo = self.cargo.clone();
     for (count, from, to) in self.moves.iter() {
       for _ in 0..*count {
@@ -97,10 +97,10 @@ impl Day for Solve {
       }
     }
 
-    Ok(Box::new(code))
+    Ok(Answer::from(code))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
    let mut cargo = self.cargo.clone();
     for (count, from, to) in self.moves.iter() {
       let mut boxes: Vec<char> = {
@@ -118,6 +118,6 @@ impl Day for Solve {
       }
     }
 
-    Ok(Box::new(code))
+    Ok(Answer::from(code))
   }
 }
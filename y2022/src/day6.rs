@@ -1,5 +1,5 @@
-use rust_util::Day;
-use std::{collections::HashSet, error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::{collections::HashSet, error::Error};
 
 pub struct Solve {
   input: String,
@@ -14,18 +14,18 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let bytes = self.input.as_bytes();
     match find_offset(bytes, 4) {
-      Some(v) => Ok(Box::new(v)),
+      Some(v) => Ok(Answer::from(v)),
       None => Err("No solution found".into()),
     }
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let bytes = self.input.as_bytes();
     match find_offset(bytes, 14) {
-      Some(v) => Ok(Box::new(v)),
+      Some(v) => Ok(Answer::from(v)),
       None => Err("No solution found".into()),
     }
   }
@@ -1,8 +1,7 @@
-use rust_util::Day;
+use rust_util::{Answer, Day};
 use std::{
   collections::{HashMap, HashSet},
   error::Error,
-  fmt::Display,
   path::PathBuf,
 };
 
@@ -107,7 +106,7 @@ fn sum_children(
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let mut total: usize = 0;
     for size in self.path_sizes.values() {
       if let DirSized::Computed(v) = size {
@@ -117,30 +116,25 @@ impl Day for Solve {
         continue;
       }
     }
-    Ok(Box::new(total))
+    Ok(Answer::from(total))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    let mut smallest = PathBuf::new();
-    smallest.push("/");
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+    let mut root = PathBuf::new();
+    root.push("/");
     let mut smallest_size: usize = 70_000_000;
-    let used = match self.path_sizes.get(&smallest).unwrap() {
+    let used = match self.path_sizes.get(&root).unwrap() {
       DirSized::Computed(v) | DirSized::Raw(v) => *v,
     };
     let space_needed = 30_000_000 - (70_000_000 - used);
-    for (path, size) in &self.path_sizes {
+    for size in self.path_sizes.values() {
       let sz = match size {
         DirSized::Computed(v) | DirSized::Raw(v) => *v,
       };
       if sz >= space_needed && sz < smallest_size {
         smallest_size = sz;
-        smallest = path.to_path_buf();
       }
     }
-    Ok(Box::new(format!(
-      "{} - {}",
-      smallest.to_string_lossy(),
-      smallest_size
-    )))
+    Ok(Answer::from(smallest_size))
   }
 }
@@ -1,8 +1,8 @@
 use crossterm::{cursor, terminal, ExecutableCommand};
 use itertools::Itertools;
-use rust_util::Day;
+use rust_util::{Answer, Day};
 use std::io::{stdout, Stdout, Write};
-use std::{collections::HashSet, error::Error, fmt::Display};
+use std::{collections::HashSet, error::Error};
 
 type Pnt = (i32, i32);
 enum Instr {
@@ -58,7 +58,7 @@ struct StdoutPrinter<'a> {
 }
 
 impl StdoutPrinter<'_> {
-  fn step(&mut self, knots: &Vec<Pnt>) {
+  fn step(&mut self, knots: &[Pnt]) {
       if !self.enabled {
           return;
       }
@@ -92,7 +92,7 @@ impl StdoutPrinter<'_> {
   }
 }
 
-fn simulate(printer: &mut StdoutPrinter, instrs: &Vec<Instr>, knot_count: usize) -> HashSet<Pnt> {
+fn simulate(printer: &mut StdoutPrinter, instrs: &[Instr], knot_count: usize) -> HashSet<Pnt> {
 let mut t_positions: HashSet<Pnt> = HashSet::new();
     let mut knots = Vec::new();
     for _ in 0..knot_count {
@@ -113,7 +113,7 @@ let mut t_positions: HashSet<Pnt> = HashSet::new();
 
         for (i1,i2) in (0..knots.len()).tuple_windows() {
           let lead_knot = knots[i1];
-          let mut tail_knot = &mut knots[i2];
+          let tail_knot = &mut knots[i2];
  
           let x_diff = lead_knot.0 - tail_knot.0;
           let y_diff = lead_knot.1 - tail_knot.1;
@@ -134,11 +134,11 @@ let mut t_positions: HashSet<Pnt> = HashSet::new();
         t_positions.insert(knots[knots.len() - 1]);
       }
     }
-    return t_positions;
+    t_positions
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let mut printer = StdoutPrinter {
       stdout: &mut stdout(),
       height: 20,
@@ -146,10 +146,10 @@ impl Day for Solve {
       enabled: false,
     };
     let t_positions = simulate(&mut printer, &self.instrs, 2);
-    Ok(Box::new(t_positions.len()))
+    Ok(Answer::from(t_positions.len()))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let mut printer = StdoutPrinter {
       stdout: &mut stdout(),
       height: 20,
@@ -157,6 +157,6 @@ impl Day for Solve {
       enabled: false,
     };
     let t_positions = simulate(&mut printer, &self.instrs, 10);
-    Ok(Box::new(t_positions.len()))
+    Ok(Answer::from(t_positions.len()))
   }
 }
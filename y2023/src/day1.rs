@@ -1,26 +1,21 @@
 use itertools::Itertools;
-use rust_util::Day;
-use std::{error::Error, fmt::Display};
+use rust_util::{Answer, BorrowedDay};
+use rust_util_macro::aoc;
+use std::error::Error;
 
-pub struct Solve {
-  input: Vec<String>,
-}
+#[aoc(title = "Trebuchet?!")]
+pub struct Solve;
 
-impl TryFrom<String> for Solve {
-  type Error = Box<dyn Error>;
+impl BorrowedDay for Solve {
+  type Parsed<'a> = Vec<&'a str>;
 
-  fn try_from(value: String) -> Result<Self, Self::Error> {
-    Ok(Solve {
-      input: value.lines().map(|s| s.to_owned()).collect_vec(),
-    })
+  fn parse(input: &str) -> Result<Vec<&str>, Box<dyn Error>> {
+    Ok(input.lines().collect_vec())
   }
-}
 
-impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(
-      self
-        .input
+  fn p1(input: &Vec<&str>) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(
+      input
         .iter()
         .filter_map(|s| {
           let nums = s.chars().filter(|x| x.is_digit(10)).collect::<Vec<char>>();
@@ -37,10 +32,9 @@ impl Day for Solve {
     ))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(
-      self
-        .input
+  fn p2(input: &Vec<&str>) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(
+      input
         .iter()
         .map(|s| {
           return parse_nums(s);
@@ -51,7 +45,7 @@ impl Day for Solve {
   }
 }
 
-fn parse_nums(s: &String) -> (String, String) {
+fn parse_nums(s: &str) -> (String, String) {
   let repl = vec![
     ("1", "1"),
     ("2", "2"),
@@ -1,5 +1,5 @@
-use rust_util::Day;
-use std::{collections::HashMap, error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::{collections::HashMap, error::Error};
 
 #[derive(Debug)]
 pub struct Solve {
@@ -120,9 +120,9 @@ fn five_x((spas, grps): &(Vec<SpaState>, Vec<usize>)) -> (Vec<SpaState>, Vec<usi
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let mut cache = HashMap::new();
-    Ok(Box::new(
+    Ok(Answer::from(
       self
         .input
         .iter()
@@ -131,9 +131,9 @@ impl Day for Solve {
     ))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let mut cache = HashMap::new();
-    Ok(Box::new(
+    Ok(Answer::from(
       self
         .input
         .iter()
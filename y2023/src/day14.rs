@@ -1,4 +1,4 @@
-use rust_util::{grid::Grid, Day};
+use rust_util::{grid::Grid, Answer, Day};
 use std::{collections::HashMap, error::Error, fmt::Display};
 
 #[derive(Debug)]
@@ -43,7 +43,7 @@ impl TryFrom<String> for Solve {
 
   fn try_from(value: String) -> Result<Self, Self::Error> {
     Ok(Solve {
-      grid: Grid::new_from(value),
+      grid: Grid::try_new_from(value)?,
     })
   }
 }
@@ -103,19 +103,19 @@ fn shift(g: &mut Grid<Tile>) {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let mut g = self.grid.clone();
     g.rot90().rot90().rot90();
     shift(&mut g);
     g.rot90();
-    Ok(Box::new(total_load(&g)))
+    Ok(Answer::from(total_load(&g)))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let max = 1_000_000_000;
     let (start, period, grids) = find_cycle(&mut self.grid.clone(), max);
     let inverted: HashMap<_, _> = grids.iter().map(|(k, v)| (v, k)).collect();
     let end = inverted.get(&(start + (max - start) % period)).unwrap();
-    Ok(Box::new(total_load(&end)))
+    Ok(Answer::from(total_load(&end)))
   }
 }
@@ -1,6 +1,6 @@
 use itertools::Itertools;
-use rust_util::Day;
-use std::{collections::VecDeque, error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::{collections::VecDeque, error::Error};
 
 impl From<&str> for Operation {
   fn from(value: &str) -> Self {
@@ -134,12 +134,12 @@ impl Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(self.vet_code()))
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(self.vet_code()))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(self.to_map().focusing_power()))
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(self.to_map().focusing_power()))
   }
 }
 
@@ -1,8 +1,8 @@
 use rust_util::{
   grid::{Dir, Grid},
-  Day,
+  Answer, Day,
 };
-use std::{collections::HashSet, error::Error, fmt::Display};
+use std::{error::Error, fmt::Display};
 
 pub struct Solve {
   grid: Grid<TileState>,
@@ -67,7 +67,7 @@ impl TryFrom<String> for Solve {
 
   fn try_from(value: String) -> Result<Self, Self::Error> {
     Ok(Solve {
-      grid: Grid::new_from(value),
+      grid: Grid::try_new_from(value)?,
     })
   }
 }
@@ -92,13 +92,17 @@ impl Solve {
   fn energize(&self, init: ((usize, usize), Dir)) -> Grid<TileState> {
     let mut grid = self.grid.clone();
 
-    let mut seen = HashSet::new();
+    // Which directions a beam has already crossed each tile in (N, E, S, W)
+    let mut seen = Grid::filled(grid.height(), grid.width(), [false; 4]);
     let mut edges = vec![init];
     while let Some(edge) = edges.pop() {
-      if seen.contains(&edge) {
+      let Some(crossed) = seen.at_mut(edge.0 .0, edge.0 .1) else {
+        continue;
+      };
+      let dir = edge.1.index().expect("Beams only move along the axes");
+      if std::mem::replace(&mut crossed[dir], true) {
         continue;
       }
-      seen.insert(edge.clone());
 
       let t = match grid.at(edge.0 .0, edge.0 .1) {
         Some(TileState::Energized(t)) => t.clone(),
@@ -108,7 +112,7 @@ impl Solve {
       grid.put(edge.0 .0, edge.0 .1, TileState::Energized(t.clone()));
       t.refract(edge.1)
         .iter()
-        .filter_map(|d| grid.step(edge.0 .0, edge.0 .1, 1, *d).map(|loc| (loc, *d)))
+        .filter_map(|d| grid.step(edge.0 .0, edge.0 .1, 1, d).map(|loc| (loc, *d)))
         .for_each(|edge| {
           edges.push(edge.clone());
         });
@@ -138,19 +142,19 @@ fn count_energized(b: &Grid<TileState>) -> usize {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(count_energized(&self.energize(((0, 0), Dir::E)))))
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(count_energized(&self.energize(((0, 0), Dir::E)))))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(format!(
-      "{:?}",
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(
       self
         .boarder()
         .iter()
         .map(|e| self.energize(e.clone()))
         .map(|b| count_energized(&b))
-        .max(),
-    )))
+        .max()
+        .ok_or("no border tiles")?,
+    ))
   }
 }
@@ -1,5 +1,5 @@
-use rust_util::{grid::Grid, search::dijkstra, Day};
-use std::{error::Error, fmt::Display};
+use rust_util::{grid::Grid, search::dijkstra, Answer, Day};
+use std::error::Error;
 
 pub struct Solve {
   grid: Grid<usize>,
@@ -10,17 +10,17 @@ impl TryFrom<String> for Solve {
 
   fn try_from(value: String) -> Result<Self, Self::Error> {
     Ok(Solve {
-      grid: Grid::new_from_map(value, |c| c.to_digit(10).unwrap() as usize),
+      grid: Grid::try_new_from_map(value, |c| c.to_digit(10).unwrap() as usize)?,
     })
   }
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(dijkstra(self.grid.clone(), 1, 3)))
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(dijkstra(self.grid.clone(), 1, 3)))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(dijkstra(self.grid.clone(), 4, 10)))
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(dijkstra(self.grid.clone(), 4, 10)))
   }
 }
@@ -1,6 +1,6 @@
 use itertools::Itertools;
-use rust_util::{grid::Dir, Day};
-use std::{error::Error, fmt::Display};
+use rust_util::{grid::Dir, Answer, Day};
+use std::error::Error;
 
 pub struct Solve {
   input: Vec<Inst>,
@@ -68,8 +68,8 @@ fn shoelace_picks(inst: Vec<(Dir, isize)>) -> isize {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(shoelace_picks(
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(shoelace_picks(
       self
         .input
         .iter()
@@ -78,8 +78,8 @@ impl Day for Solve {
     )))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(shoelace_picks(
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(shoelace_picks(
       self
         .input
         .iter()
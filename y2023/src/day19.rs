@@ -1,5 +1,5 @@
-use rust_util::Day;
-use std::{collections::HashMap, error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::{collections::HashMap, error::Error};
 
 pub struct Solve {
   parts: Vec<Part>,
@@ -183,8 +183,8 @@ impl Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(
       self
         .parts
         .iter()
@@ -194,7 +194,7 @@ impl Day for Solve {
     ))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let mut finals = Vec::new();
     let mut frontier = vec![("in".to_string(), Ranges::default())];
     while let Some((ptr, ranges)) = frontier.pop() {
@@ -228,7 +228,7 @@ impl Day for Solve {
         }
       }
     }
-    Ok(Box::new(finals.iter().map(|rg| rg.combos()).sum::<usize>()))
+    Ok(Answer::from(finals.iter().map(|rg| rg.combos()).sum::<usize>()))
   }
 }
 
@@ -1,9 +1,8 @@
 use itertools::Itertools;
-use rust_util::Day;
+use rust_util::{Answer, Day};
 use std::{
   collections::{HashMap, VecDeque},
   error::Error,
-  fmt::Display,
 };
 
 #[derive(Debug)]
@@ -132,9 +131,9 @@ impl Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let mut mod_state = self.mods.clone();
-    Ok(Box::new(
+    Ok(Answer::from(
       (0..1000)
         .map(|_| self.push_button(&mut mod_state))
         .reduce(|(low, high), (nl, nh)| (low + nl, high + nh))
@@ -143,7 +142,7 @@ impl Day for Solve {
     ))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(1))
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+    Err(rust_util::Error::Unsolved.into())
   }
 }
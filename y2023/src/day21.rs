@@ -1,7 +1,4 @@
-use rust_util::{
-  grid::{Dir, Grid},
-  Day,
-};
+use rust_util::{grid::Grid, Answer, Day};
 use std::{
   collections::{HashSet, VecDeque},
   error::Error,
@@ -58,13 +55,13 @@ impl TryFrom<String> for Solve {
       .collect();
     Ok(Solve {
       start,
-      grid: Grid::new(board),
+      grid: Grid::try_new(board)?,
     })
   }
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let max_steps = 64;
     let mut dest: HashSet<(usize, usize)> = HashSet::new();
     let mut seen: HashSet<((usize, usize), usize)> = HashSet::new();
@@ -75,23 +72,23 @@ impl Day for Solve {
         continue;
       }
       seen.insert((loc, steps));
-      [Dir::N, Dir::E, Dir::S, Dir::W]
-        .iter()
-        .filter_map(|d| self.grid.at_step(loc.0, loc.1, 1, *d))
-        .filter(|(_, t)| **t != Tile::Rock)
-        .for_each(|(loc, _)| {
+      self
+        .grid
+        .neighbors4(loc.0, loc.1)
+        .filter(|(_, _, t)| **t != Tile::Rock)
+        .for_each(|(loc, _, _)| {
           if steps + 1 == max_steps {
-            dest.insert(loc.clone());
+            dest.insert(loc);
           } else {
-            frontier.push_back((loc.clone(), steps + 1));
+            frontier.push_back((loc, steps + 1));
           }
         });
     }
 
-    Ok(Box::new(dest.len()))
+    Ok(Answer::from(dest.len()))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(1))
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+    Err(rust_util::Error::Unsolved.into())
   }
 }
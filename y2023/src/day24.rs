@@ -1,6 +1,6 @@
 use itertools::Itertools;
-use rust_util::Day;
-use std::{error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::error::Error;
 
 pub struct Solve {
   input: Vec<Vector>,
@@ -93,7 +93,7 @@ impl Vector {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let rg = 200000000000000..=400000000000000;
     let mut count = 0;
     for i in 0..self.input.len() {
@@ -113,11 +113,11 @@ impl Day for Solve {
         }
       }
     }
-    Ok(Box::new(count))
+    Ok(Answer::from(count))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(1))
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+    Err(rust_util::Error::Unsolved.into())
   }
 }
 
@@ -1,10 +1,9 @@
 use itertools::Itertools;
 use rand::{rngs::ThreadRng, seq::IteratorRandom, thread_rng};
-use rust_util::Day;
+use rust_util::{Answer, Day};
 use std::{
   collections::{HashMap, HashSet, VecDeque},
   error::Error,
-  fmt::Display,
 };
 
 pub struct Solve {
@@ -125,17 +124,18 @@ impl Graph {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
     let edges = self.input.find_edges(&self);
     println!("Found: {:?}", edges);
     let mut graph = self.input.clone();
     edges.iter().for_each(|e| graph.disconnect(e));
     let g1 = graph.group_size(edges[0].0);
     let g2 = graph.group_size(edges[0].1);
-    Ok(Box::new(g1 * g2))
+    Ok(Answer::from(g1 * g2))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(1))
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+    // Day 25 has no part 2
+    Err(rust_util::Error::Unsolved.into())
   }
 }
@@ -1,9 +1,8 @@
 use itertools::Itertools;
-use rust_util::Day;
+use rust_util::{Answer, Day};
 use std::{
   collections::{HashMap, HashSet},
   error::Error,
-  fmt::Display,
 };
 
 #[derive(Debug)]
@@ -52,8 +51,8 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(
       self
         .cards
         .iter()
@@ -62,8 +61,8 @@ impl Day for Solve {
     ))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(
       self
         .cards
         .iter()
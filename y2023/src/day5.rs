@@ -1,6 +1,6 @@
 use itertools::Itertools;
-use rust_util::Day;
-use std::{collections::HashMap, error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::{collections::HashMap, error::Error};
 
 #[derive(Debug)]
 pub struct Solve {
@@ -159,29 +159,31 @@ impl Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(format!(
-      "{:?}",
-      self.find_min_loc_splits(
-        self
-          .seeds
-          .iter()
-          .map(|s| Range::of_len(*s, 1))
-          .collect_vec()
-      )
-    )))
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(
+      self
+        .find_min_loc_splits(
+          self
+            .seeds
+            .iter()
+            .map(|s| Range::of_len(*s, 1))
+            .collect_vec(),
+        )
+        .ok_or("no seed locations")?,
+    ))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
     let seed_ranges = self
       .seeds
       .chunks_exact(2)
       .map(|win| Range::of_len(win[0], win[1]))
       .collect_vec();
 
-    Ok(Box::new(format!(
-      "{:?}",
-      self.find_min_loc_splits(seed_ranges)
-    )))
+    Ok(Answer::from(
+      self
+        .find_min_loc_splits(seed_ranges)
+        .ok_or("no seed locations")?,
+    ))
   }
 }
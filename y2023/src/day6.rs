@@ -1,6 +1,6 @@
 use itertools::Itertools;
-use rust_util::Day;
-use std::{error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::error::Error;
 
 #[derive(Debug)]
 pub struct Solve {
@@ -65,19 +65,19 @@ impl Race {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(format!(
-      "{:?}",
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(
       self
         .races
         .iter()
         .map(Race::solve)
         .map(|v| v.len())
-        .reduce(|a, b| a * b),
-    )))
+        .reduce(|a, b| a * b)
+        .ok_or("no races")?,
+    ))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(format!("{:?}", self.big_race.solve().len())))
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(self.big_race.solve().len()))
   }
 }
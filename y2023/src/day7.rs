@@ -1,6 +1,6 @@
 use itertools::Itertools;
-use rust_util::Day;
-use std::{cmp::Ordering, error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::{cmp::Ordering, error::Error};
 
 #[derive(Debug)]
 pub struct Solve {
@@ -168,11 +168,11 @@ impl Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(self.total_winnings(false)))
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(self.total_winnings(false)))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(self.total_winnings(true)))
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(self.total_winnings(true)))
   }
 }
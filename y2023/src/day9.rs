@@ -1,6 +1,6 @@
 use itertools::Itertools;
-use rust_util::Day;
-use std::{error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::error::Error;
 
 pub struct Solve {
   firsts: Vec<Vec<i64>>,
@@ -53,25 +53,25 @@ impl Solve {
 }
 
 impl Day for Solve {
-  fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(format!(
-      "{:?}",
+  fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(
       self
         .lasts
         .iter()
         .map(|r| r.iter().rev().fold(0, |delta, b| delta + b))
-        .reduce(|a, b| a + b),
-    )))
+        .reduce(|a, b| a + b)
+        .ok_or("no histories")?,
+    ))
   }
 
-  fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-    Ok(Box::new(format!(
-      "{:?}",
+  fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+    Ok(Answer::from(
       self
         .firsts
         .iter()
         .map(|r| r.iter().rev().fold(0, |delta, b| b - delta))
-        .reduce(|a, b| a + b),
-    )))
+        .reduce(|a, b| a + b)
+        .ok_or("no histories")?,
+    ))
   }
 }
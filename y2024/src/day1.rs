@@ -1,6 +1,6 @@
 use itertools::Itertools;
-use rust_util::Day;
-use std::{error::Error, fmt::Display, ops::Sub};
+use rust_util::{Answer, Day};
+use std::{error::Error, ops::Sub};
 
 pub struct Solve {
     left: Vec<i64>,
@@ -25,7 +25,7 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
         let ans = self
             .left
             .iter()
@@ -33,10 +33,10 @@ impl Day for Solve {
             .zip(self.right.iter().sorted())
             .map(|(l, r)| l.max(&r).sub(l.min(&r)))
             .sum::<i64>();
-        Ok(Box::new(ans))
+        Ok(Answer::from(ans))
     }
 
-    fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
         let sim_score = self
             .right
             .iter()
@@ -48,6 +48,6 @@ impl Day for Solve {
             .iter()
             .filter_map(|v| sim_score.get(v).map(|sim| sim * v))
             .sum::<i64>();
-        Ok(Box::new(ans))
+        Ok(Answer::from(ans))
     }
 }
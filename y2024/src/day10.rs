@@ -1,11 +1,7 @@
-use rust_util::{
-    grid::{Dir, Grid},
-    Day,
-};
+use rust_util::{grid::Grid, Answer, Day};
 use std::{
     collections::{HashSet, VecDeque},
     error::Error,
-    fmt::Display,
 };
 
 pub struct Solve {
@@ -16,7 +12,7 @@ impl TryFrom<String> for Solve {
     type Error = Box<dyn Error>;
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
-        let map = Grid::new_from_map(value, |c| c.to_digit(10).unwrap());
+        let map = Grid::try_new_from_map(value, |c| c.to_digit(10).unwrap())?;
         let (peaks, trails) = get_trail_heads(&map)
             .iter()
             .map(|loc| unique_trails_from(loc, &map))
@@ -33,12 +29,12 @@ impl TryFrom<String> for Solve {
     }
 }
 impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(Box::new(self.peaks))
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(Answer::from(self.peaks))
     }
 
-    fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(Box::new(self.trails))
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(Answer::from(self.trails))
     }
 }
 
@@ -55,22 +51,20 @@ fn unique_trails_from(
     let mut frontier = VecDeque::from_iter(vec![vec![(loc.0, loc.1, *loc.2)]]);
     while let Some(path) = frontier.pop_front() {
         let (y, x, h) = path.last().unwrap(); // Never empty
-        for dir in [Dir::E, Dir::W, Dir::N, Dir::S] {
-            if let Some((loc, h1)) = map.at_step(*y, *x, 1, &dir) {
-                if *h1 != h + 1 {
-                    // not a valid step to take
-                    continue;
-                }
-                let mut new_path = path.clone();
-                new_path.push((loc.0, loc.1, *h1));
-                if *h1 == 9 {
-                    // Goal found, push to results
-                    ret.insert(new_path);
-                    continue;
-                } else {
-                    // Otherwise we can step this way on our hike
-                    frontier.push_back(new_path);
-                }
+        for (loc, _, h1) in map.neighbors4(*y, *x) {
+            if *h1 != h + 1 {
+                // not a valid step to take
+                continue;
+            }
+            let mut new_path = path.clone();
+            new_path.push((loc.0, loc.1, *h1));
+            if *h1 == 9 {
+                // Goal found, push to results
+                ret.insert(new_path);
+                continue;
+            } else {
+                // Otherwise we can step this way on our hike
+                frontier.push_back(new_path);
             }
         }
     }
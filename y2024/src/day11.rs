@@ -1,5 +1,5 @@
-use rust_util::Day;
-use std::{collections::HashMap, error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::{collections::HashMap, error::Error};
 
 pub struct Solve {
     stones: HashMap<usize, usize>,
@@ -20,12 +20,12 @@ impl TryFrom<String> for Solve {
     }
 }
 impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(Box::new(blink(&self.stones, 25)))
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(Answer::from(blink(&self.stones, 25)))
     }
 
-    fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(Box::new(blink(&self.stones, 75)))
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(Answer::from(blink(&self.stones, 75)))
     }
 }
 
@@ -1,5 +1,5 @@
-use rust_util::Day;
-use std::{error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::error::Error;
 
 struct System {
     x: [i64; 3],
@@ -83,14 +83,14 @@ fn det(x1: i64, x2: i64, y1: i64, y2: i64) -> i64 {
 }
 
 impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(Box::new(
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(Answer::from(
             self.systems.iter().filter_map(|s| s.solve(0)).sum::<i64>(),
         ))
     }
 
-    fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(Box::new(
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(Answer::from(
             self.systems
                 .iter()
                 .filter_map(|s| s.solve(10000000000000))
@@ -1,69 +1,63 @@
 use crossterm::{cursor, terminal, ExecutableCommand};
 use itertools::Itertools;
-use rust_util::Day;
+use rust_util::{
+    parse::{parse_lines, Mode},
+    Answer, Day, Point,
+};
+use rust_util_macro::aoc;
 use std::{
+    collections::HashSet,
     error::Error,
-    fmt::Display,
     io::{stdout, Stdout, Write},
 };
 
 #[derive(Debug, Clone)]
 struct Bot {
-    p: (i64, i64),
-    v: (i64, i64),
+    p: Point,
+    v: Point,
 }
+#[aoc(title = "Restroom Redoubt", slow)]
 pub struct Solve {
     bots: Vec<Bot>,
+    /// Width and height of the floor, which the bots wrap around
+    size: Point,
 }
 impl TryFrom<String> for Solve {
     type Error = Box<dyn Error>;
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
         Ok(Solve {
-            bots: value
-                .lines()
-                .filter_map(|l| {
-                    let (p, v) = l.split_once(" v=")?;
-                    let (x, y) = p.strip_prefix("p=")?.split_once(",")?;
-                    let (dx, dy) = v.split_once(",")?;
+            bots: parse_lines(&value, Mode::Strict, |l| {
+                let (p, v) = l.split_once(l.text, " v=")?;
+                let (x, y) = l.split_once(l.strip_prefix(p, "p=")?, ",")?;
+                let (dx, dy) = l.split_once(v, ",")?;
 
-                    Some(Bot {
-                        p: (x.parse().ok()?, y.parse().ok()?),
-                        v: (dx.parse().ok()?, dy.parse().ok()?),
-                    })
+                Ok(Bot {
+                    p: Point::new(l.parse(x)?, l.parse(y)?),
+                    v: Point::new(l.parse(dx)?, l.parse(dy)?),
                 })
-                .collect(),
+            })?,
+            size: Point::new(101, 103),
         })
     }
 }
 
-fn wrap(v: i64, m: i64) -> i64 {
-    if v < 0 {
-        m + v
-    } else {
-        v
-    }
-}
-
 impl Bot {
-    pub fn sim(&self, secs: i64, xm: i64, ym: i64) -> Bot {
+    pub fn sim(&self, secs: i64, size: Point) -> Bot {
         Bot {
-            p: (
-                wrap((self.p.0 + self.v.0 * secs) % xm, xm),
-                wrap((self.p.1 + self.v.1 * secs) % ym, ym),
-            ),
+            p: (self.p + self.v * secs).rem_euclid(size),
             v: self.v,
         }
     }
 
     pub fn quad(&self, xh: i64, yh: i64) -> Option<u8> {
-        if self.p.0 < xh && self.p.1 < yh {
+        if self.p.x < xh && self.p.y < yh {
             Some(1)
-        } else if self.p.0 < xh && self.p.1 > yh {
+        } else if self.p.x < xh && self.p.y > yh {
             Some(2)
-        } else if self.p.0 > xh && self.p.1 < yh {
+        } else if self.p.x > xh && self.p.y < yh {
             Some(3)
-        } else if self.p.0 > xh && self.p.1 > yh {
+        } else if self.p.x > xh && self.p.y > yh {
             Some(4)
         } else {
             None
@@ -72,11 +66,11 @@ impl Bot {
 }
 
 impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(Box::new(
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(Answer::from(
             self.bots
                 .iter()
-                .map(|b| b.sim(100, 101, 103))
+                .map(|b| b.sim(100, self.size))
                 .filter_map(|b| b.quad(50, 51))
                 .into_grouping_map_by(|q| *q)
                 .fold(0, |acc, _, _| acc + 1)
@@ -85,10 +79,9 @@ impl Day for Solve {
         ))
     }
 
-    fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
         let mut printer = StdoutPrinter {
-            height: 103,
-            width: 101,
+            size: self.size,
             stdout: &mut stdout(),
             enabled: true,
         };
@@ -96,19 +89,19 @@ impl Day for Solve {
         let brkpt = 103 * 101;
         let mut cnt = 43;
         let step = 103;
-        let mut bots = self.bots.iter().map(|b| b.sim(cnt, 101, 103)).collect();
+        let mut bots = self.bots.iter().map(|b| b.sim(cnt, self.size)).collect();
         while cnt < brkpt {
             printer.print(&bots, cnt, sleep);
-            bots = bots.iter().map(|b| b.sim(step, 101, 103)).collect();
+            bots = bots.iter().map(|b| b.sim(step, self.size)).collect();
             cnt += step;
         }
-        Ok(Box::new(1))
+        // Not solved, find the tree in the printed frames
+        Err(rust_util::Error::Unsolved.into())
     }
 }
 
 struct StdoutPrinter<'a> {
-    height: i64,
-    width: i64,
+    size: Point,
     stdout: &'a mut Stdout,
     enabled: bool,
 }
@@ -119,26 +112,18 @@ impl StdoutPrinter<'_> {
             return;
         }
         self.stdout
-            .execute(cursor::MoveUp((self.height + 1) as u16))
+            .execute(cursor::MoveUp((self.size.y + 1) as u16))
             .unwrap();
         self.stdout
             .execute(terminal::Clear(terminal::ClearType::FromCursorDown))
             .unwrap();
 
-        for y in 0..self.height {
-            for x in 0..self.width {
-                let mut written = false;
-                for b in bots.iter() {
-                    let kx = b.p.0;
-                    let ky = b.p.1;
-                    if x == kx && y == ky {
-                        write!(self.stdout, "{}", "*").unwrap();
-                        written = true;
-                        break;
-                    }
-                }
-                if !written {
-                    write!(self.stdout, ".").unwrap();
+        let taken: HashSet<Point> = bots.iter().map(|b| b.p).collect();
+        for y in 0..self.size.y {
+            for x in 0..self.size.x {
+                match taken.contains(&Point::new(x, y)) {
+                    true => write!(self.stdout, "*").unwrap(),
+                    false => write!(self.stdout, ".").unwrap(),
                 }
             }
             writeln!(self.stdout).unwrap();
@@ -148,3 +133,18 @@ impl StdoutPrinter<'_> {
         std::thread::sleep(std::time::Duration::from_millis(sleep));
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rejects_malformed_bots() {
+        let err = Solve::try_from("p=0,4 v=3,-3\np=6,3 v=-1,x\n".to_string())
+            .err()
+            .unwrap();
+        assert!(err
+            .to_string()
+            .starts_with("Parse failed at line 2, column 12 (`p=6,3 v=-1,x`)"));
+    }
+}
@@ -1,8 +1,8 @@
 use rust_util::{
     grid::{Dir, Grid},
-    Day,
+    Answer, Day,
 };
-use std::{collections::HashSet, error::Error, fmt::Display};
+use std::{collections::HashSet, error::Error};
 
 pub struct Solve {
     maze: String,
@@ -30,7 +30,7 @@ impl TryFrom<String> for Solve {
     }
 }
 impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
         let mut mgrid = Grid::new_from(self.maze.clone());
         let start = mgrid
             .iter()
@@ -38,7 +38,7 @@ impl Day for Solve {
             .map(|(y, x, _)| (y, x))
             .ok_or("@ not found")?;
         let end = apply(start, &mut mgrid, &self.instructions);
-        Ok(Box::new(
+        Ok(Answer::from(
             end.iter()
                 .filter(|(_, _, c)| **c == 'O')
                 .map(|(y, x, _)| y * 100 + x)
@@ -46,7 +46,7 @@ impl Day for Solve {
         ))
     }
 
-    fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
         let mut mgrid = Grid::new_from(
             self.maze
                 .chars()
@@ -66,7 +66,7 @@ impl Day for Solve {
             .map(|(y, x, _)| (y, x))
             .ok_or("@ not found")?;
         let end = apply(start, &mut mgrid, &self.instructions);
-        Ok(Box::new(
+        Ok(Answer::from(
             end.iter()
                 .filter(|(_, _, c)| **c == '[')
                 .map(|(y, x, _)| y * 100 + x)
@@ -1,22 +1,22 @@
 use rust_util::grid::{Dir, Grid};
-use rust_util::Day;
+use rust_util::{Answer, Day};
 use std::{
     cmp::Reverse,
     collections::{BinaryHeap, HashMap},
 };
-use std::{error::Error, fmt::Display};
+use std::error::Error;
 
-type Loc = (usize, usize);
+pub(crate) type Loc = (usize, usize);
 pub struct Solve {
-    grid: Grid<char>,
-    start: Loc,
-    end: Loc,
+    pub(crate) grid: Grid<char>,
+    pub(crate) start: Loc,
+    pub(crate) end: Loc,
 }
 impl TryFrom<String> for Solve {
     type Error = Box<dyn Error>;
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
-        let grid = Grid::new_from(value);
+        let grid = Grid::try_new_from(value)?;
         let start_pos = grid
             .iter()
             .find_map(|(y, x, c)| match c {
@@ -39,16 +39,16 @@ impl TryFrom<String> for Solve {
     }
 }
 impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(Box::new(dijkstra(
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(Answer::from(dijkstra(
             &self.grid,
             (self.start, Dir::E),
             &self.end,
         )))
     }
 
-    fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(Box::new(1))
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+        Err(rust_util::Error::Unsolved.into())
     }
 }
 
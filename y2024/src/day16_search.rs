@@ -0,0 +1,67 @@
+use crate::day16::{self, Loc};
+use rust_util::grid::Dir;
+use rust_util::{search::shortest_path, Answer, Day};
+use std::error::Error;
+
+/// Day 16 part 1 on `rust_util::search::shortest_path` rather than its own
+/// dijkstra. Part 2 isn't a variant, it defers to day 16's (still unsolved) one.
+pub struct Solve(day16::Solve);
+impl TryFrom<String> for Solve {
+    type Error = Box<dyn Error>;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Ok(Solve(day16::Solve::try_from(value)?))
+    }
+}
+impl Day for Solve {
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+        let grid = &self.0.grid;
+        let cost = shortest_path(
+            (self.0.start, Dir::E),
+            |(loc, _)| *loc == self.0.end,
+            |&((y, x), d): &(Loc, Dir)| {
+                let dirs = Dir::CARDINAL.into_iter().filter(move |dir| *dir != d.rev());
+                grid.neighbors_with(y, x, dirs)
+                    .filter_map(move |(loc, dir, t)| match t {
+                        '#' => None,
+                        // Turning costs 1000 on top of the step
+                        _ => Some(((loc, dir), if dir == d { 1 } else { 1001 })),
+                    })
+            },
+        );
+        Ok(Answer::from(cost.ok_or("Did not find path to target")?))
+    }
+
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+        self.0.p2()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn matches_day16() {
+        let input = "###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############
+";
+        let base = day16::Solve::try_from(input.to_string()).unwrap();
+        let variant = Solve::try_from(input.to_string()).unwrap();
+        assert_eq!(variant.p1().unwrap(), 7036);
+        assert_eq!(variant.p1().unwrap(), base.p1().unwrap());
+    }
+}
@@ -1,5 +1,5 @@
-use rust_util::Day;
-use std::{error::Error, fmt::Display, str::Lines};
+use rust_util::{Answer, Day};
+use std::{error::Error, str::Lines};
 
 pub struct Solve {
     registers: Registers,
@@ -36,10 +36,10 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
         let mut regs = self.registers.clone();
         let mut tape = self.tape.clone();
-        Ok(Box::new(
+        Ok(Answer::from(
             run(&mut tape, &mut regs)
                 .iter()
                 .map(|v| format!("{v}"))
@@ -48,8 +48,8 @@ impl Day for Solve {
         ))
     }
 
-    fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(Box::new(1))
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+        Err(rust_util::Error::Unsolved.into())
     }
 }
 
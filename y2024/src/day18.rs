@@ -1,8 +1,7 @@
-use rust_util::{grid::Dir, Day};
+use rust_util::{grid::Dir, Answer, Day};
 use std::{
     collections::{HashMap, HashSet, VecDeque},
     error::Error,
-    fmt::Display,
 };
 
 type Loc = (usize, usize);
@@ -24,12 +23,12 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
         let walls = mk_walls(&self.bytes, 1024);
-        Ok(Box::new(find_path(&walls).unwrap()))
+        Ok(Answer::from(find_path(&walls).unwrap()))
     }
 
-    fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
         let falling = 1024;
         let mut walls = mk_walls(&self.bytes, falling);
         for (y, x) in &self.bytes[falling..] {
@@ -40,10 +39,11 @@ impl Day for Solve {
                 })
                 .or_insert_with(|| HashSet::from_iter(vec![*x]));
             if find_path(&walls) == None {
-                return Ok(Box::new(format!("{x},{y}")));
+                return Ok(Answer::from(format!("{x},{y}")));
             }
         }
-        Ok(Box::new(0))
+        // No byte cuts off the exit
+        Err(rust_util::Error::Unsolved.into())
     }
 }
 
@@ -1,5 +1,5 @@
-use rust_util::Day;
-use std::{collections::HashMap, error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::{collections::HashMap, error::Error};
 
 type TType = String;
 type Arrangement = String;
@@ -27,8 +27,8 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(Box::new(
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(Answer::from(
             self.arrangements
                 .iter()
                 .filter(|a| is_possible(a, &self.types))
@@ -36,9 +36,9 @@ impl Day for Solve {
         ))
     }
 
-    fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
         let mut memo = HashMap::new();
-        Ok(Box::new(
+        Ok(Answer::from(
             self.arrangements
                 .iter()
                 .map(|a| count_possible(a, &mut memo, &self.types))
@@ -1,5 +1,5 @@
 use itertools::Itertools;
-use rust_util::Day;
+use rust_util::{Answer, Day};
 use std::error::Error;
 
 pub struct Solve {
@@ -24,8 +24,8 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn std::fmt::Display>, Box<dyn Error>> {
-        Ok(Box::new(
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(Answer::from(
             self.reports
                 .iter()
                 .filter(|report| is_safe_itn(true, &report))
@@ -33,8 +33,8 @@ impl Day for Solve {
         ))
     }
 
-    fn p2(&self) -> Result<Box<dyn std::fmt::Display>, Box<dyn Error>> {
-        Ok(Box::new(
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(Answer::from(
             self.reports
                 .iter()
                 .filter(|report| is_safe_itn(false, &report))
@@ -1,8 +1,5 @@
 use itertools::Itertools;
-use rust_util::{
-    grid::{Dir, Grid},
-    Day,
-};
+use rust_util::{grid::Grid, Answer, Day};
 use std::{
     collections::{HashMap, VecDeque},
     error::Error,
@@ -40,13 +37,13 @@ impl TryFrom<String> for Solve {
     type Error = Box<dyn Error>;
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
-        let grid = Grid::new_from_map(value, |c| match c {
+        let grid = Grid::try_new_from_map(value, |c| match c {
             '#' => Tile::Wall,
             '.' => Tile::Space,
             'E' => Tile::End,
             'S' => Tile::Start,
             x => unreachable!("Invalid char hit: {x}"),
-        });
+        })?;
         let start = grid
             .iter()
             .find_map(|(y, x, t)| match *t == Tile::Start {
@@ -59,13 +56,14 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        let ans = find_shortcuts(&get_dists(&self.grid, &self.start));
-        Ok(Box::new(format!("P1: {}, P2: {}", ans.0, ans.1)))
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+        let (short_2s, _) = find_shortcuts(&get_dists(&self.grid, &self.start));
+        Ok(Answer::from(short_2s))
     }
 
-    fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(Box::new(1))
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+        let (_, short_20s) = find_shortcuts(&get_dists(&self.grid, &self.start));
+        Ok(Answer::from(short_20s))
     }
 }
 
@@ -74,17 +72,15 @@ fn get_dists(grid: &Grid<Tile>, start: &Loc) -> HashMap<Loc, usize> {
     dists.insert(*start, 0);
     let mut frontier = VecDeque::from_iter(vec![(0, *start)]);
     while let Some((cost_to_here, (y, x))) = frontier.pop_front() {
-        for dir in [Dir::N, Dir::S, Dir::E, Dir::W] {
-            if let Some(((ny, nx), t)) = grid.at_step(y, x, 1, &dir) {
-                if *t == Tile::Wall {
-                    continue;
-                }
-                if dists.contains_key(&(ny, nx)) {
-                    continue;
-                }
-                dists.insert((ny, nx), cost_to_here + 1);
-                frontier.push_back((cost_to_here + 1, (ny, nx)));
+        for ((ny, nx), _, t) in grid.neighbors4(y, x) {
+            if *t == Tile::Wall {
+                continue;
+            }
+            if dists.contains_key(&(ny, nx)) {
+                continue;
             }
+            dists.insert((ny, nx), cost_to_here + 1);
+            frontier.push_back((cost_to_here + 1, (ny, nx)));
         }
     }
     dists
@@ -1,5 +1,5 @@
-use rust_util::Day;
-use std::{collections::HashMap, error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::{collections::HashMap, error::Error};
 
 pub struct Solve {
     snums: Vec<i64>,
@@ -17,8 +17,8 @@ impl TryFrom<String> for Solve {
     }
 }
 impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(Box::new(
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(Answer::from(
             self.snums
                 .iter()
                 .map(|n| (0..2000).fold(*n, |acc, _| secret(acc)))
@@ -26,8 +26,8 @@ impl Day for Solve {
         ))
     }
 
-    fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(Box::new(most_banana(
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(Answer::from(most_banana(
             &self.snums.iter().map(|n| price_deltas(*n, 2000)).collect(),
         )))
     }
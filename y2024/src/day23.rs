@@ -1,9 +1,8 @@
 use itertools::Itertools;
-use rust_util::Day;
+use rust_util::{Answer, Day};
 use std::{
     collections::{HashMap, HashSet},
     error::Error,
-    fmt::Display,
 };
 
 type NetId = String;
@@ -35,12 +34,12 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(Box::new(t_triplets(&self.adjs)))
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(Answer::from(t_triplets(&self.adjs)))
     }
 
-    fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(Box::new(largest_clique(&self.adjs)))
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(Answer::from(largest_clique(&self.adjs)))
     }
 }
 
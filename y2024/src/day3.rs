@@ -1,5 +1,5 @@
 use core::str;
-use rust_util::Day;
+use rust_util::{Answer, Day};
 use std::error::Error;
 
 pub struct Solve {
@@ -18,9 +18,9 @@ const OPS_P1: [&'static str; 1] = ["mul("];
 const OPS_P2: [&'static str; 3] = ["mul(", "don't(", "do("];
 
 impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn std::fmt::Display>, Box<dyn Error>> {
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
         let input = self.input.as_bytes();
-        Ok(Box::new(
+        Ok(Answer::from(
             try_through_eof(input, OPS_P1.to_vec())
                 .iter()
                 .filter_map(|op| match op {
@@ -31,7 +31,7 @@ impl Day for Solve {
         ))
     }
 
-    fn p2(&self) -> Result<Box<dyn std::fmt::Display>, Box<dyn Error>> {
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
         let input = self.input.as_bytes();
         let mut total = 0;
         let mut ddo = true;
@@ -45,7 +45,7 @@ impl Day for Solve {
                 _ => {}
             }
         }
-        Ok(Box::new(total))
+        Ok(Answer::from(total))
     }
 }
 
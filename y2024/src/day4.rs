@@ -1,6 +1,6 @@
 use std::error::Error;
 
-use rust_util::Day;
+use rust_util::{Answer, Day};
 
 #[derive(Debug, Eq, PartialEq)]
 pub struct Solve {
@@ -25,8 +25,8 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn std::fmt::Display>, Box<dyn std::error::Error>> {
-        Ok(Box::new(find(
+    fn p1(&self) -> Result<Answer, Box<dyn std::error::Error>> {
+        Ok(Answer::from(find(
             &self,
             vec![['X', 'M', 'A', 'S']],
             'X',
@@ -34,8 +34,8 @@ impl Day for Solve {
         )))
     }
 
-    fn p2(&self) -> Result<Box<dyn std::fmt::Display>, Box<dyn std::error::Error>> {
-        Ok(Box::new(find(
+    fn p2(&self) -> Result<Answer, Box<dyn std::error::Error>> {
+        Ok(Answer::from(find(
             &self,
             vec![
                 ['M', 'M', 'A', 'S', 'S'],
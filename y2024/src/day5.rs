@@ -1,5 +1,5 @@
 use itertools::Itertools;
-use rust_util::Day;
+use rust_util::{Answer, Day};
 use std::{
     cmp::Ordering,
     collections::{HashMap, HashSet},
@@ -52,9 +52,9 @@ fn make_before_set(items: Vec<u32>) -> HashMap<u32, HashSet<u32>> {
 }
 
 impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn std::fmt::Display>, Box<dyn std::error::Error>> {
+    fn p1(&self) -> Result<Answer, Box<dyn std::error::Error>> {
         // Invalid prints are items whose "beforeValues" intersect with the associated rule's values.
-        Ok(Box::new(
+        Ok(Answer::from(
             self.prints
                 .iter()
                 .filter(|(_, p)| print_is_valid(p, &self.rules))
@@ -63,10 +63,10 @@ impl Day for Solve {
         ))
     }
 
-    fn p2(&self) -> Result<Box<dyn std::fmt::Display>, Box<dyn std::error::Error>> {
+    fn p2(&self) -> Result<Answer, Box<dyn std::error::Error>> {
         // Now find all the invalid pages (dropping valid ones)
         // Re-order them to be valid & sum their middle pages
-        Ok(Box::new(
+        Ok(Answer::from(
             self.prints
                 .iter()
                 .filter(|(_, p)| !print_is_valid(p, &self.rules))
@@ -1,9 +1,8 @@
 use itertools::Itertools;
-use rust_util::Day;
+use rust_util::{Answer, Day};
 use std::{
     collections::{HashMap, HashSet},
     error::Error,
-    fmt::Display,
 };
 
 pub struct Solve {
@@ -60,8 +59,8 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(Box::new(
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(Answer::from(
             get_turns(
                 &self.patrol,
                 &self.by_x,
@@ -79,8 +78,8 @@ impl Day for Solve {
         ))
     }
 
-    fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(Box::new(
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(Answer::from(
             // Walk the path, at each step add a block in all 3 directions and finish the walk (test for loop).
             // Keep those that cause loop.
             // I have a bug and am too tired to know why. But for some reason the code
@@ -1,5 +1,5 @@
-use rust_util::Day;
-use std::{collections::VecDeque, error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::{collections::VecDeque, error::Error};
 
 pub struct Solve {
     eqs: Vec<Equation>,
@@ -37,15 +37,15 @@ impl TryFrom<String> for Solve {
     }
 }
 impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(Box::new(sum_solvable_totals(
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(Answer::from(sum_solvable_totals(
             &self.eqs,
             &vec![Operator::Add, Operator::Mul],
         )))
     }
 
-    fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(Box::new(sum_solvable_totals(
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(Answer::from(sum_solvable_totals(
             &self.eqs,
             &vec![Operator::Add, Operator::Mul, Operator::Cat],
         )))
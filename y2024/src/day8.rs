@@ -1,9 +1,8 @@
 use itertools::Itertools;
-use rust_util::Day;
+use rust_util::{Answer, Day};
 use std::{
     collections::{HashMap, HashSet},
     error::Error,
-    fmt::Display,
 };
 
 #[derive(Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
@@ -42,16 +41,16 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(Box::new(
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(Answer::from(
             anti_nodes(self, find_anti_nodes)
                 .collect::<HashSet<_>>()
                 .len(),
         ))
     }
 
-    fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(Box::new(
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(Answer::from(
             anti_nodes(self, find_harmonic_nodes)
                 .collect::<HashSet<_>>()
                 .len(),
@@ -1,6 +1,6 @@
 use itertools::Itertools;
-use rust_util::Day;
-use std::{cmp::Ordering, collections::VecDeque, error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::{cmp::Ordering, collections::VecDeque, error::Error};
 
 // ID, start(incl), end(excl)
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -39,15 +39,15 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(Box::new(checksum(&compact_blocks(
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(Answer::from(checksum(&compact_blocks(
             self.files.clone(),
             self.free.clone(),
         ))))
     }
 
-    fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(Box::new(checksum(&compact_files(
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(Answer::from(checksum(&compact_files(
             self.files.clone(),
             self.free.clone(),
         ))))
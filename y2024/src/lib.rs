@@ -0,0 +1,5 @@
+extern crate rust_util;
+
+use rust_util_macro::import_aoc_solutions;
+
+import_aoc_solutions!();
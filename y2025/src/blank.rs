@@ -1,39 +0,0 @@
-use rust_util::Day;
-use std::{error::Error, fmt::Display};
-
-pub struct Solve {}
-impl TryFrom<String> for Solve {
-    type Error = Box<dyn Error>;
-
-    fn try_from(value: String) -> Result<Self, Self::Error> {
-        Ok(Solve {})
-    }
-}
-impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(Box::new(1))
-    }
-
-    fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(Box::new(1))
-    }
-}
-
-#[cfg(test)]
-mod test {
-    use super::*;
-
-    #[test]
-    fn example() {
-        let input = "".to_string();
-        let solve = Solve::try_from(input).unwrap();
-        assert_eq!(
-            format!("{}", solve.p1().unwrap()).parse::<i64>().unwrap(),
-            1
-        );
-        assert_eq!(
-            format!("{}", solve.p2().unwrap()).parse::<i64>().unwrap(),
-            1
-        );
-    }
-}
@@ -1,5 +1,5 @@
-use rust_util::Day;
-use std::{error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::error::Error;
 
 pub struct Solve {
     value: Vec<isize>,
@@ -25,7 +25,7 @@ impl TryFrom<String> for Solve {
     }
 }
 impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
         let cnt = self
             .value
             .iter()
@@ -37,10 +37,10 @@ impl Day for Solve {
                 acc
             })
             .0;
-        Ok(Box::new(cnt))
+        Ok(Answer::from(cnt))
     }
 
-    fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
         let cnt = self
             .value
             .iter()
@@ -57,7 +57,7 @@ impl Day for Solve {
                 acc
             })
             .0;
-        Ok(Box::new(cnt))
+        Ok(Answer::from(cnt))
     }
 }
 
@@ -69,13 +69,7 @@ mod test {
     fn example() {
         let input = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82".to_string();
         let solve = Solve::try_from(input).unwrap();
-        assert_eq!(
-            format!("{}", solve.p1().unwrap()).parse::<i64>().unwrap(),
-            3
-        );
-        assert_eq!(
-            format!("{}", solve.p2().unwrap()).parse::<i64>().unwrap(),
-            6
-        );
+        assert_eq!(solve.p1().unwrap(), 3);
+        assert_eq!(solve.p2().unwrap(), 6);
     }
 }
@@ -1,9 +1,9 @@
 use itertools::Itertools;
-use rust_util::Day;
+use rust_util::{Answer, Day};
+use rust_util_macro::aoc;
 use std::{
     collections::{HashSet, VecDeque},
     error::Error,
-    fmt::Display,
 };
 
 // Don't forget these are "backwards" (right to left)
@@ -11,6 +11,7 @@ type Indicators = u16; // Bit array; [.##.] == 0110
 type Button = u16; // Bit array; (0,2,3,4) == 10111
 type Joltage = u128; // u9 array (up to 10, 9 bits wide each for 512 max value)
 
+#[aoc(title = "Factory", slow)]
 pub struct Solve {
     input: Vec<(Indicators, Vec<Button>, Joltage)>,
 }
@@ -69,8 +70,8 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(Box::new(
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(Answer::from(
             self.input
                 .iter()
                 .map(|(i, bs, _)| solve_indicator(*i, bs))
@@ -78,8 +79,8 @@ impl Day for Solve {
         ))
     }
 
-    fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(Box::new(
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(Answer::from(
             self.input
                 .iter()
                 .map(|(_, bs, j)| solve_joltage(*j, bs))
@@ -185,13 +186,7 @@ mod test {
             "
         .to_string();
         let solve = Solve::try_from(input).unwrap();
-        assert_eq!(
-            format!("{}", solve.p1().unwrap()).parse::<i64>().unwrap(),
-            7
-        );
-        assert_eq!(
-            format!("{}", solve.p2().unwrap()).parse::<i64>().unwrap(),
-            33
-        );
+        assert_eq!(solve.p1().unwrap(), 7);
+        assert_eq!(solve.p2().unwrap(), 33);
     }
 }
@@ -1,5 +1,5 @@
-use rust_util::Day;
-use std::{collections::HashMap, error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::{collections::HashMap, error::Error};
 
 pub struct Solve {
     nodes: HashMap<String, Vec<String>>,
@@ -46,14 +46,14 @@ impl Solve {
     }
 }
 impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
         let mut memo = HashMap::new();
         let count = self.count_paths("you", &mut memo);
-        Ok(Box::new(count))
+        Ok(Answer::from(count))
     }
 
-    fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(Box::new(1))
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+        Err(rust_util::Error::Unsolved.into())
     }
 }
 
@@ -77,13 +77,6 @@ iii: out
             "
         .to_string();
         let solve = Solve::try_from(input).unwrap();
-        assert_eq!(
-            format!("{}", solve.p1().unwrap()).parse::<i64>().unwrap(),
-            5
-        );
-        assert_eq!(
-            format!("{}", solve.p2().unwrap()).parse::<i64>().unwrap(),
-            1
-        );
+        assert_eq!(solve.p1().unwrap(), 5);
     }
 }
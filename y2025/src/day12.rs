@@ -1,6 +1,6 @@
 use itertools::Itertools;
-use rust_util::Day;
-use std::{error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::error::Error;
 
 pub struct Solve {
     regions: Vec<(usize, usize, Vec<usize>)>,
@@ -32,8 +32,8 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(Box::new(
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(Answer::from(
             self.regions
                 .iter()
                 .filter(|(w, l, bxs)| w * l >= bxs.iter().sum::<usize>() * 9)
@@ -41,7 +41,7 @@ impl Day for Solve {
         ))
     }
 
-    fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(Box::new(1))
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
+        Err(rust_util::Error::Unsolved.into())
     }
 }
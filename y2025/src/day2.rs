@@ -1,5 +1,5 @@
-use rust_util::Day;
-use std::{error::Error, fmt::Display, ops::RangeInclusive};
+use rust_util::{Answer, Day};
+use std::{error::Error, ops::RangeInclusive};
 
 pub struct Solve {
     ranges: Vec<RangeInclusive<usize>>,
@@ -45,7 +45,7 @@ fn num(n: usize, p: u32, p_n: u32) -> usize {
 }
 
 impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
         let mut sum = 0;
         for r in self.ranges.iter() {
             // Only need to check the first "half" of the number range
@@ -64,10 +64,10 @@ impl Day for Solve {
                 sum += n;
             }
         }
-        Ok(Box::new(sum))
+        Ok(Answer::from(sum))
     }
 
-    fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
         let mut sum = 0;
         for r in self.ranges.iter() {
             for s in r.clone() {
@@ -89,7 +89,7 @@ impl Day for Solve {
                 }
             }
         }
-        Ok(Box::new(sum))
+        Ok(Answer::from(sum))
     }
 }
 
@@ -116,13 +116,7 @@ mod test {
         let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124"
             .to_string();
         let solve = Solve::try_from(input).unwrap();
-        assert_eq!(
-            format!("{}", solve.p1().unwrap()).parse::<i64>().unwrap(),
-            1227775554
-        );
-        assert_eq!(
-            format!("{}", solve.p2().unwrap()).parse::<i64>().unwrap(),
-            4174379265
-        );
+        assert_eq!(solve.p1().unwrap(), 1227775554);
+        assert_eq!(solve.p2().unwrap(), 4174379265i64);
     }
 }
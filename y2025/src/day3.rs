@@ -1,5 +1,5 @@
-use rust_util::Day;
-use std::{error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::error::Error;
 
 pub struct Solve {
     shelves: Vec<Vec<u8>>,
@@ -47,9 +47,9 @@ impl TryFrom<String> for Solve {
     }
 }
 impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
         let bz = 2;
-        Ok(Box::new(
+        Ok(Answer::from(
             self.shelves
                 .iter()
                 .map(|s| min_max_fast(s, bz))
@@ -58,9 +58,9 @@ impl Day for Solve {
         ))
     }
 
-    fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
         let bz = 12;
-        Ok(Box::new(
+        Ok(Answer::from(
             self.shelves
                 .iter()
                 .map(|s| min_max_fast(s, bz))
@@ -84,13 +84,7 @@ mod test {
             "
         .to_string();
         let solve = Solve::try_from(input).unwrap();
-        assert_eq!(
-            format!("{}", solve.p1().unwrap()).parse::<i64>().unwrap(),
-            357
-        );
-        assert_eq!(
-            format!("{}", solve.p2().unwrap()).parse::<i64>().unwrap(),
-            3121910778619
-        );
+        assert_eq!(solve.p1().unwrap(), 357);
+        assert_eq!(solve.p2().unwrap(), 3121910778619i64);
     }
 }
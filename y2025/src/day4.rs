@@ -1,7 +1,4 @@
-use rust_util::{
-    grid::{Dir, Grid},
-    Day,
-};
+use rust_util::{grid::Grid, Answer, Day};
 use std::{error::Error, fmt::Display};
 
 pub struct Solve {
@@ -40,7 +37,7 @@ impl TryFrom<String> for Solve {
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
         Ok(Solve {
-            grid: Grid::new_from(value),
+            grid: Grid::try_new_from(value)?,
         })
     }
 }
@@ -51,20 +48,10 @@ fn removable(grid: &Grid<Tile>) -> Vec<(usize, usize)> {
             if **t == Tile::Empty {
                 return false;
             }
-            let rolls_around = [
-                Dir::N,
-                Dir::E,
-                Dir::S,
-                Dir::W,
-                Dir::NE,
-                Dir::NW,
-                Dir::SE,
-                Dir::SW,
-            ]
-            .iter()
-            .filter_map(|d| grid.at_step(*y, *x, 1, d))
-            .filter(|(_, t)| **t == Tile::Roll)
-            .count();
+            let rolls_around = grid
+                .neighbors8(*y, *x)
+                .filter(|(_, _, t)| **t == Tile::Roll)
+                .count();
             rolls_around < 4
         })
         .map(|(y, x, _)| (y, x))
@@ -78,11 +65,11 @@ fn remove(grid: &mut Grid<Tile>, pos: &Vec<(usize, usize)>) {
 }
 
 impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(Box::new(removable(&self.grid).len()))
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(Answer::from(removable(&self.grid).len()))
     }
 
-    fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
         let mut grid = self.grid.clone();
         let mut removed = 0;
         loop {
@@ -93,7 +80,7 @@ impl Day for Solve {
             removed += rm.len();
             remove(&mut grid, &rm);
         }
-        Ok(Box::new(removed))
+        Ok(Answer::from(removed))
     }
 }
 
@@ -115,13 +102,7 @@ mod test {
 @.@.@@@.@."
             .to_string();
         let solve = Solve::try_from(input).unwrap();
-        assert_eq!(
-            format!("{}", solve.p1().unwrap()).parse::<i64>().unwrap(),
-            13
-        );
-        assert_eq!(
-            format!("{}", solve.p2().unwrap()).parse::<i64>().unwrap(),
-            43
-        );
+        assert_eq!(solve.p1().unwrap(), 13);
+        assert_eq!(solve.p2().unwrap(), 43);
     }
 }
@@ -1,6 +1,6 @@
 use itertools::Itertools;
-use rust_util::Day;
-use std::{error::Error, fmt::Display, ops::RangeInclusive};
+use rust_util::{Answer, Day};
+use std::{error::Error, ops::RangeInclusive};
 
 pub struct Solve {
     ranges: Vec<RangeInclusive<usize>>,
@@ -27,8 +27,8 @@ impl TryFrom<String> for Solve {
     }
 }
 impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
-        Ok(Box::new(
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(Answer::from(
             self.ids
                 .iter()
                 .filter(|id| self.ranges.iter().any(|r| r.contains(id)))
@@ -36,14 +36,14 @@ impl Day for Solve {
         ))
     }
 
-    fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
         // Sort ranges by start index
         // For any ranges whose start is in the prior range, fuse into that range
         // map to size of ranges
         // sum.
         let mut ranges = self.ranges.clone();
         ranges.sort_by_key(|r| *r.start());
-        Ok(Box::new(
+        Ok(Answer::from(
             ranges
                 .iter()
                 .fold(vec![RangeInclusive::new(0, 0)], |mut acc, r| {
@@ -86,13 +86,7 @@ mod test {
 "
         .to_string();
         let solve = Solve::try_from(input).unwrap();
-        assert_eq!(
-            format!("{}", solve.p1().unwrap()).parse::<i64>().unwrap(),
-            3
-        );
-        assert_eq!(
-            format!("{}", solve.p2().unwrap()).parse::<i64>().unwrap(),
-            14
-        );
+        assert_eq!(solve.p1().unwrap(), 3);
+        assert_eq!(solve.p2().unwrap(), 14);
     }
 }
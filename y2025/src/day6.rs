@@ -1,6 +1,6 @@
 use itertools::Itertools;
-use rust_util::Day;
-use std::{error::Error, fmt::Display};
+use rust_util::{Answer, Day};
+use std::error::Error;
 
 pub struct Solve {
     value: String,
@@ -55,7 +55,7 @@ impl TryFrom<String> for Solve {
     }
 }
 impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
         let lines = self
             .value
             .trim()
@@ -79,10 +79,10 @@ impl Day for Solve {
                     .collect()
             })
             .collect();
-        Ok(Box::new(problems.iter().map(|p| eval(p)).sum::<usize>()))
+        Ok(Answer::from(problems.iter().map(|p| eval(p)).sum::<usize>()))
     }
 
-    fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
         let (h, w) = self
             .value
             .trim_matches('\n')
@@ -114,7 +114,7 @@ impl Day for Solve {
             }
         }
         problems.push(p.clone());
-        Ok(Box::new(problems.iter().map(|p| eval(p)).sum::<usize>()))
+        Ok(Answer::from(problems.iter().map(|p| eval(p)).sum::<usize>()))
     }
 }
 
@@ -132,13 +132,7 @@ mod test {
             "
         .to_string();
         let solve = Solve::try_from(input).unwrap();
-        assert_eq!(
-            format!("{}", solve.p1().unwrap()).parse::<i64>().unwrap(),
-            4277556
-        );
-        assert_eq!(
-            format!("{}", solve.p2().unwrap()).parse::<i64>().unwrap(),
-            3263827
-        );
+        assert_eq!(solve.p1().unwrap(), 4277556);
+        assert_eq!(solve.p2().unwrap(), 3263827);
     }
 }
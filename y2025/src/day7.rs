@@ -1,8 +1,7 @@
-use rust_util::Day;
+use rust_util::{Answer, Day};
 use std::{
     collections::{HashMap, HashSet},
     error::Error,
-    fmt::Display,
 };
 
 pub struct Solve {
@@ -53,7 +52,7 @@ impl Solve {
 }
 
 impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
         let mut splits = 0;
         let mut hit_splits = HashSet::new();
         let mut frontier = vec![self.root];
@@ -70,13 +69,13 @@ impl Day for Solve {
                 frontier.push(r);
             }
         }
-        Ok(Box::new(splits))
+        Ok(Answer::from(splits))
     }
 
-    fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
         let mut memo: HashMap<(usize, usize), usize> = HashMap::new();
         let total_paths = count(&self, &mut memo, self.root);
-        Ok(Box::new(total_paths))
+        Ok(Answer::from(total_paths))
     }
 }
 
@@ -124,13 +123,7 @@ mod test {
             "
         .to_string();
         let solve = Solve::try_from(input).unwrap();
-        assert_eq!(
-            format!("{}", solve.p1().unwrap()).parse::<i64>().unwrap(),
-            21
-        );
-        assert_eq!(
-            format!("{}", solve.p2().unwrap()).parse::<i64>().unwrap(),
-            40
-        );
+        assert_eq!(solve.p1().unwrap(), 21);
+        assert_eq!(solve.p2().unwrap(), 40);
     }
 }
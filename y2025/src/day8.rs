@@ -1,9 +1,8 @@
 use itertools::Itertools;
-use rust_util::Day;
+use rust_util::{Answer, Day};
 use std::{
     collections::{HashMap, HashSet},
     error::Error,
-    fmt::Display,
 };
 
 #[derive(Clone, Default, Debug, Eq, Hash, PartialEq)]
@@ -131,10 +130,10 @@ fn link_until(
 }
 
 impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
         let (mut members, mut clusters) = init_mappings(&self.pts);
         let _ = link_until(&self.pts, &mut members, &mut clusters, Some(1000));
-        Ok(Box::new(
+        Ok(Answer::from(
             clusters
                 .values()
                 .sorted_by_key(|c| c.len())
@@ -144,9 +143,9 @@ impl Day for Solve {
         ))
     }
 
-    fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
         let (mut members, mut clusters) = init_mappings(&self.pts);
         let (i, j) = link_until(&self.pts, &mut members, &mut clusters, None);
-        Ok(Box::new(i.0 * j.0))
+        Ok(Answer::from(i.0 * j.0))
     }
 }
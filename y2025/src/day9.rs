@@ -1,11 +1,12 @@
 #![allow(warnings)]
-use rust_util::Day;
+use rust_util::{Answer, Day};
+use rust_util_macro::aoc;
 use std::{
     collections::{HashSet, VecDeque},
     error::Error,
-    fmt::Display,
 };
 
+#[aoc(title = "Movie Theater", slow)]
 pub struct Solve {
     tiles: Vec<(usize, usize)>,
 }
@@ -26,7 +27,7 @@ impl TryFrom<String> for Solve {
 }
 
 impl Day for Solve {
-    fn p1(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+    fn p1(&self) -> Result<Answer, Box<dyn Error>> {
         let mut max_area = 0;
         for i in 0..self.tiles.len() {
             for j in i..self.tiles.len() {
@@ -36,10 +37,10 @@ impl Day for Solve {
                 }
             }
         }
-        Ok(Box::new(max_area))
+        Ok(Answer::from(max_area))
     }
 
-    fn p2(&self) -> Result<Box<dyn Display>, Box<dyn Error>> {
+    fn p2(&self) -> Result<Answer, Box<dyn Error>> {
         // Now we have to ensure the rectangles are within the confines of the overall
         // shape the coordinates creates, we can't create rectangles whom leave that space.
         //
@@ -148,7 +149,7 @@ impl Day for Solve {
         //     println!("{total}/{trem}");
         // }
         println!("{max_corn:?}");
-        Ok(Box::new(max_area))
+        Ok(Answer::from(max_area))
     }
 }
 
@@ -214,13 +215,7 @@ mod test {
             "
         .to_string();
         let solve = Solve::try_from(input).unwrap();
-        assert_eq!(
-            format!("{}", solve.p1().unwrap()).parse::<i64>().unwrap(),
-            50
-        );
-        assert_eq!(
-            format!("{}", solve.p2().unwrap()).parse::<i64>().unwrap(),
-            24
-        );
+        assert_eq!(solve.p1().unwrap(), 50);
+        assert_eq!(solve.p2().unwrap(), 24);
     }
 }